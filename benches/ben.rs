@@ -1,5 +1,12 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use hydrolox_pga3d::{motor::Motor, point::Point};
+use hydrolox_pga3d::{
+    motor::Motor,
+    point::Point,
+    rotor::Rotor,
+    simd::{MotorX4, MotorX8, PointX4, PointX8},
+    translator::Translator,
+    Float,
+};
 use rand::Rng;
 use std::hint::black_box;
 
@@ -19,7 +26,7 @@ fn criterion_benchmark(c: &mut Criterion) {
 
     c.bench_function("random combine and transform", |b| {
         b.iter(|| {
-            let axis: (f32, f32, f32) = rng.random();
+            let axis: (Float, Float, Float) = rng.random();
             let magnitude = (axis.0 * axis.0 + axis.1 * axis.1 + axis.2 * axis.2).sqrt();
             Motor::from_translation(rng.random(), rng.random(), rng.random())
                 .combine(Motor::from_rotation_around_axis(
@@ -35,6 +42,105 @@ fn criterion_benchmark(c: &mut Criterion) {
                 ));
         });
     });
+
+    let rotor = Rotor::from_euler_angles(0.4, 1.3, -0.8);
+    let translator = Translator::from_translation(1.0, -2.0, 3.5);
+    let rotor_motor = Motor::from(rotor);
+    let translator_motor = Motor::from(translator);
+    let motor = Motor::from_euler_pos_and_rot(3.0, 2.0, 1.0, 2.5, 3.0, 4.0);
+
+    c.bench_function("combine translator and rotor as motors", |b| {
+        b.iter(|| black_box(translator_motor).combine(black_box(rotor_motor)))
+    });
+    c.bench_function("combine translator and rotor", |b| {
+        b.iter(|| black_box(translator).combine_rotor(black_box(rotor)))
+    });
+
+    c.bench_function("combine motor and rotor as motors", |b| {
+        b.iter(|| black_box(motor).combine(black_box(rotor_motor)))
+    });
+    c.bench_function("combine motor and rotor", |b| {
+        b.iter(|| black_box(motor).combine_rotor(black_box(rotor)))
+    });
+
+    c.bench_function("transform point by rotor as motor", |b| {
+        b.iter(|| black_box(rotor_motor).transform(black_box(p1)))
+    });
+    c.bench_function("transform point by rotor", |b| {
+        b.iter(|| black_box(rotor).transform(black_box(p1)))
+    });
+
+    c.bench_function("transform point by translator as motor", |b| {
+        b.iter(|| black_box(translator_motor).transform(black_box(p1)))
+    });
+    c.bench_function("transform point by translator", |b| {
+        b.iter(|| black_box(translator).transform(black_box(p1)))
+    });
+
+    let points: [Point; 8] =
+        std::array::from_fn(|i| Point::from_position(i as Float, 1.0, -(i as Float)));
+    let motors: [Motor; 8] = std::array::from_fn(|i| {
+        Motor::from_euler_pos_and_rot(i as Float, 2.0, 3.0, 0.1 * i as Float, 0.2, 0.3)
+    });
+    let points_x4 = PointX4::from_points([points[0], points[1], points[2], points[3]]);
+    let motors_x4 = MotorX4::from_motors([motors[0], motors[1], motors[2], motors[3]]);
+    let points_x8 = PointX8::from_points(points);
+    let motors_x8 = MotorX8::from_motors(motors);
+
+    c.bench_function("transform 8 points scalar", |b| {
+        b.iter(|| {
+            let points = black_box(points);
+            let motors = black_box(motors);
+            std::array::from_fn::<Point, 8, _>(|i| motors[i].transform(points[i]))
+        })
+    });
+    c.bench_function("transform 8 points x4", |b| {
+        b.iter(|| {
+            (
+                black_box(motors_x4).transform(&black_box(points_x4)),
+                black_box(motors_x4).transform(&black_box(points_x4)),
+            )
+        })
+    });
+    c.bench_function("transform 8 points x8", |b| {
+        b.iter(|| black_box(motors_x8).transform(&black_box(points_x8)))
+    });
+
+    c.bench_function("combine 8 motors scalar", |b| {
+        b.iter(|| {
+            let motors = black_box(motors);
+            std::array::from_fn::<Motor, 8, _>(|i| motors[i].combine(motors[7 - i]))
+        })
+    });
+    c.bench_function("combine 8 motors x8", |b| {
+        b.iter(|| black_box(motors_x8).combine(&black_box(motors_x8)))
+    });
+
+    c.bench_function("join 8 points scalar", |b| {
+        b.iter(|| {
+            let points = black_box(points);
+            std::array::from_fn::<_, 8, _>(|i| points[i].join(points[7 - i]))
+        })
+    });
+    c.bench_function("join 8 points x8", |b| {
+        b.iter(|| black_box(points_x8).join(&black_box(points_x8)))
+    });
+
+    let many_points: Vec<Point> = (0..100_000)
+        .map(|i| Point::from_position(i as Float, 0.5, -(i as Float)))
+        .collect();
+    let mut many_out = many_points.clone();
+
+    c.bench_function("transform 100k points one by one", |b| {
+        b.iter(|| {
+            for (o, p) in many_out.iter_mut().zip(&many_points) {
+                *o = black_box(motor).transform(*p);
+            }
+        })
+    });
+    c.bench_function("transform 100k points slice", |b| {
+        b.iter(|| black_box(motor).transform_slice(&many_points, &mut many_out))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
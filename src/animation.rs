@@ -0,0 +1,249 @@
+use crate::{direction::Direction, line::Line, motor::Motor, point::Point, Float};
+
+//How a key blends into the next one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    //Holds the value until the next key
+    Step,
+    #[default]
+    Linear,
+    //Cubic Hermite using the key's out tangent and the next key's in tangent
+    Cubic,
+}
+
+//What sampling does before the first key and after the last
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WrapMode {
+    #[default]
+    Clamp,
+    //Repeats the span from the first key to the last
+    Loop,
+}
+
+//Values that tracks can blend. Tangents are rates of change per unit of time
+pub trait Keyframe: Copy + std::fmt::Debug + PartialEq {
+    type Tangent: Copy + std::fmt::Debug + PartialEq;
+    const ZERO_TANGENT: Self::Tangent;
+    fn lerp(&self, other: Self, t: Float) -> Self;
+    //Duration is the time between the two keys, which scales tangents into the unit segment
+    fn hermite(
+        &self,
+        out_tangent: Self::Tangent,
+        other: Self,
+        in_tangent: Self::Tangent,
+        t: Float,
+        duration: Float,
+    ) -> Self;
+}
+
+//Positions blend in Euclidean space and come out with unit weight
+impl Keyframe for Point {
+    type Tangent = Direction;
+    const ZERO_TANGENT: Direction = Direction::ZERO;
+    fn lerp(&self, other: Self, t: Float) -> Self {
+        let (a, b) = (
+            self.scaled().to_direction_unchecked(),
+            other.scaled().to_direction_unchecked(),
+        );
+        position(a + (b - a) * t)
+    }
+    fn hermite(
+        &self,
+        out_tangent: Direction,
+        other: Self,
+        in_tangent: Direction,
+        t: Float,
+        duration: Float,
+    ) -> Self {
+        let (a, b) = (
+            self.scaled().to_direction_unchecked(),
+            other.scaled().to_direction_unchecked(),
+        );
+        let [h00, h10, h01, h11] = hermite_basis(t);
+        position(a * h00 + out_tangent * (h10 * duration) + b * h01 + in_tangent * (h11 * duration))
+    }
+}
+
+//Motors blend along screws. Tangents are world frame twists in the units of Motor::log, and the
+//curve is built in the log chart around the earlier key, so the tangent at the later key is only
+//matched approximately when the keys are far apart
+impl Keyframe for Motor {
+    type Tangent = Line;
+    const ZERO_TANGENT: Line = Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    fn lerp(&self, other: Self, t: Float) -> Self {
+        self.interpolate(other, t)
+    }
+    fn hermite(
+        &self,
+        out_tangent: Line,
+        other: Self,
+        in_tangent: Line,
+        t: Float,
+        duration: Float,
+    ) -> Self {
+        let delta = self.inverse().combine(other).log();
+        let [_, h10, h01, h11] = hermite_basis(t);
+        let twist = out_tangent * (h10 * duration) + delta * h01 + in_tangent * (h11 * duration);
+        self.combine(Motor::exp(twist))
+    }
+}
+
+fn position(d: Direction) -> Point {
+    Point::from_position(d.x, d.y, d.z)
+}
+fn hermite_basis(t: Float) -> [Float; 4] {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    [
+        2.0 * t3 - 3.0 * t2 + 1.0,
+        t3 - 2.0 * t2 + t,
+        -2.0 * t3 + 3.0 * t2,
+        t3 - t2,
+    ]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Key<T: Keyframe> {
+    pub time: Float,
+    pub value: T,
+    pub interpolation: Interpolation,
+    pub in_tangent: T::Tangent,
+    pub out_tangent: T::Tangent,
+}
+impl<T: Keyframe> Key<T> {
+    pub fn new(time: Float, value: T, interpolation: Interpolation) -> Self {
+        Self {
+            time,
+            value,
+            interpolation,
+            in_tangent: T::ZERO_TANGENT,
+            out_tangent: T::ZERO_TANGENT,
+        }
+    }
+    pub fn cubic(time: Float, value: T, in_tangent: T::Tangent, out_tangent: T::Tangent) -> Self {
+        Self {
+            time,
+            value,
+            interpolation: Interpolation::Cubic,
+            in_tangent,
+            out_tangent,
+        }
+    }
+}
+
+//Remembers the last segment sampled, so playing a track forwards finds each segment in constant
+//time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Cursor {
+    segment: usize,
+}
+
+//Keys sorted by time
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "Key<T>: serde::Serialize",
+        deserialize = "Key<T>: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Track<T: Keyframe> {
+    keys: Vec<Key<T>>,
+    pub wrap: WrapMode,
+}
+pub type MotorTrack = Track<Motor>;
+pub type PointTrack = Track<Point>;
+
+impl<T: Keyframe> Default for Track<T> {
+    fn default() -> Self {
+        Self::new(WrapMode::Clamp)
+    }
+}
+impl<T: Keyframe> Track<T> {
+    pub fn new(wrap: WrapMode) -> Self {
+        Self {
+            keys: Vec::new(),
+            wrap,
+        }
+    }
+    pub fn from_keys(mut keys: Vec<Key<T>>, wrap: WrapMode) -> Self {
+        keys.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Self { keys, wrap }
+    }
+
+    pub fn keys(&self) -> &[Key<T>] {
+        &self.keys
+    }
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+    //Start and end times, None for an empty track
+    pub fn range(&self) -> Option<(Float, Float)> {
+        Some((self.keys.first()?.time, self.keys.last()?.time))
+    }
+
+    //Replaces any key at the same time
+    pub fn insert(&mut self, key: Key<T>) {
+        let i = self.keys.partition_point(|k| k.time < key.time);
+        if i < self.keys.len() && self.keys[i].time == key.time {
+            self.keys[i] = key;
+        } else {
+            self.keys.insert(i, key);
+        }
+    }
+    pub fn remove(&mut self, index: usize) -> Key<T> {
+        self.keys.remove(index)
+    }
+
+    pub fn sample(&self, time: Float) -> Option<T> {
+        self.sample_with(&mut Cursor::default(), time)
+    }
+    //Samples starting the search from the cursor's segment. Any cursor works with any track
+    pub fn sample_with(&self, cursor: &mut Cursor, time: Float) -> Option<T> {
+        let (first, last) = self.range()?;
+        let n = self.keys.len();
+        let time = match self.wrap {
+            WrapMode::Loop if last > first => first + (time - first).rem_euclid(last - first),
+            _ => time,
+        };
+        if time <= first {
+            return Some(self.keys[0].value);
+        }
+        if time >= last {
+            return Some(self.keys[n - 1].value);
+        }
+
+        //Keys segment and segment + 1 bracket the time
+        let mut segment = cursor.segment.min(n - 2);
+        if !(self.keys[segment].time <= time && time < self.keys[segment + 1].time) {
+            if segment + 2 < n
+                && self.keys[segment + 1].time <= time
+                && time < self.keys[segment + 2].time
+            {
+                segment += 1;
+            } else {
+                segment = self.keys.partition_point(|k| k.time <= time) - 1;
+            }
+        }
+        cursor.segment = segment;
+
+        let (a, b) = (&self.keys[segment], &self.keys[segment + 1]);
+        let duration = b.time - a.time;
+        let t = (time - a.time) / duration;
+        Some(match a.interpolation {
+            Interpolation::Step => a.value,
+            Interpolation::Linear => a.value.lerp(b.value, t),
+            Interpolation::Cubic => {
+                a.value
+                    .hermite(a.out_tangent, b.value, b.in_tangent, t, duration)
+            }
+        })
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{direction::Direction, line::Line, motor::Motor, plane::Plane, point::Point, Float};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+//Slices at least this long are split across threads when the rayon feature is enabled
+#[cfg(feature = "rayon")]
+pub const PARALLEL_THRESHOLD: usize = 16 * 1024;
+
+//A motor expanded into a rotation matrix and translation, which is the cheapest form to apply
+//to many elements
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MotorMatrix {
+    pub r: [[Float; 3]; 3],
+    pub t: [Float; 3],
+}
+impl MotorMatrix {
+    #[inline]
+    pub fn new(m: &Motor) -> Self {
+        let s = 2.0 / m.weight_norm_squared();
+        let t = m.translation_euler();
+
+        let xx = m.vx * m.vx;
+        let yy = m.vy * m.vy;
+        let zz = m.vz * m.vz;
+        let xy = m.vx * m.vy;
+        let xz = m.vx * m.vz;
+        let yz = m.vy * m.vz;
+        let wx = m.vw * m.vx;
+        let wy = m.vw * m.vy;
+        let wz = m.vw * m.vz;
+
+        Self {
+            r: [
+                [1.0 - s * (yy + zz), s * (xy - wz), s * (xz + wy)],
+                [s * (xy + wz), 1.0 - s * (xx + zz), s * (yz - wx)],
+                [s * (xz - wy), s * (yz + wx), 1.0 - s * (xx + yy)],
+            ],
+            t: [t.x, t.y, t.z],
+        }
+    }
+
+    #[inline(always)]
+    pub fn rotate(&self, x: Float, y: Float, z: Float) -> [Float; 3] {
+        let r = &self.r;
+        [
+            r[0][0] * x + r[0][1] * y + r[0][2] * z,
+            r[1][0] * x + r[1][1] * y + r[1][2] * z,
+            r[2][0] * x + r[2][1] * y + r[2][2] * z,
+        ]
+    }
+
+    #[inline(always)]
+    pub fn transform(&self, p: Point) -> Point {
+        let [x, y, z] = self.rotate(p.x, p.y, p.z);
+        Point::new(
+            x + self.t[0] * p.w,
+            y + self.t[1] * p.w,
+            z + self.t[2] * p.w,
+            p.w,
+        )
+    }
+    #[inline(always)]
+    pub fn transform_direction(&self, d: Direction) -> Direction {
+        let [x, y, z] = self.rotate(d.x, d.y, d.z);
+        Direction::new(x, y, z)
+    }
+    #[inline(always)]
+    pub fn transform_plane(&self, p: Plane) -> Plane {
+        let [x, y, z] = self.rotate(p.x, p.y, p.z);
+        Plane::new(
+            x,
+            y,
+            z,
+            p.w - (x * self.t[0] + y * self.t[1] + z * self.t[2]),
+        )
+    }
+    #[inline(always)]
+    pub fn transform_line(&self, l: Line) -> Line {
+        let [vx, vy, vz] = self.rotate(l.vx, l.vy, l.vz);
+        let [mx, my, mz] = self.rotate(l.mx, l.my, l.mz);
+        let t = &self.t;
+        Line::new(
+            vx,
+            vy,
+            vz,
+            mx + t[1] * vz - t[2] * vy,
+            my + t[2] * vx - t[0] * vz,
+            mz + t[0] * vy - t[1] * vx,
+        )
+    }
+}
+
+#[inline]
+fn map_slice<T: Copy + Send + Sync>(src: &[T], dst: &mut [T], f: impl Fn(T) -> T + Send + Sync) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "Source and destination lengths differ"
+    );
+    #[cfg(feature = "rayon")]
+    if src.len() >= PARALLEL_THRESHOLD {
+        dst.par_iter_mut()
+            .zip(src.par_iter())
+            .for_each(|(d, s)| *d = f(*s));
+        return;
+    }
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = f(*s);
+    }
+}
+
+#[inline]
+fn map_in_place<T: Copy + Send + Sync>(items: &mut [T], f: impl Fn(T) -> T + Send + Sync) {
+    #[cfg(feature = "rayon")]
+    if items.len() >= PARALLEL_THRESHOLD {
+        items.par_iter_mut().for_each(|i| *i = f(*i));
+        return;
+    }
+    for i in items {
+        *i = f(*i);
+    }
+}
+
+impl Motor {
+    pub fn transform_slice(&self, src: &[Point], dst: &mut [Point]) {
+        let m = MotorMatrix::new(self);
+        map_slice(src, dst, |p| m.transform(p));
+    }
+    pub fn transform_slice_in_place(&self, points: &mut [Point]) {
+        let m = MotorMatrix::new(self);
+        map_in_place(points, |p| m.transform(p));
+    }
+
+    pub fn transform_direction_slice(&self, src: &[Direction], dst: &mut [Direction]) {
+        let m = MotorMatrix::new(self);
+        map_slice(src, dst, |d| m.transform_direction(d));
+    }
+    pub fn transform_direction_slice_in_place(&self, directions: &mut [Direction]) {
+        let m = MotorMatrix::new(self);
+        map_in_place(directions, |d| m.transform_direction(d));
+    }
+
+    pub fn transform_plane_slice(&self, src: &[Plane], dst: &mut [Plane]) {
+        let m = MotorMatrix::new(self);
+        map_slice(src, dst, |p| m.transform_plane(p));
+    }
+    pub fn transform_plane_slice_in_place(&self, planes: &mut [Plane]) {
+        let m = MotorMatrix::new(self);
+        map_in_place(planes, |p| m.transform_plane(p));
+    }
+
+    pub fn transform_line_slice(&self, src: &[Line], dst: &mut [Line]) {
+        let m = MotorMatrix::new(self);
+        map_slice(src, dst, |l| m.transform_line(l));
+    }
+    pub fn transform_line_slice_in_place(&self, lines: &mut [Line]) {
+        let m = MotorMatrix::new(self);
+        map_in_place(lines, |l| m.transform_line(l));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{line::Line, motor::Motor, Float};
+
+//Averages of weighted motors. Weights don't need to sum to 1, but their total has to be positive
+
+//Normalized sum, after flipping each motor onto the same half of the double cover as the first.
+//Cheap and close to the mean for motors that are near each other, drifting away from it as they
+//spread out
+pub fn blend(motors: &[(Motor, Float)]) -> Option<Motor> {
+    let (first, _) = motors.first()?;
+    let mut sum = [0.0; 8];
+    for (m, w) in motors {
+        let dot = m.vx * first.vx + m.vy * first.vy + m.vz * first.vz + m.vw * first.vw;
+        let w = if dot < 0.0 { -w } else { *w };
+        let c = [m.vx, m.vy, m.vz, m.vw, m.mx, m.my, m.mz, m.mw];
+        for (s, c) in sum.iter_mut().zip(c) {
+            *s += c * w;
+        }
+    }
+    let [vx, vy, vz, vw, mx, my, mz, mw] = sum;
+    let sum = Motor::new(vx, vy, vz, vw, mx, my, mz, mw);
+    (sum.weight_norm_squared() > 0.0).then(|| sum.normalized())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mean {
+    pub motor: Motor,
+    pub iterations: usize,
+    //Size of the last correction, as the norms of its twist's direction and moment added together
+    pub residual: Float,
+    pub converged: bool,
+}
+
+//The bi-invariant mean, where the weighted logs of the motors relative to the mean sum to zero.
+//Starts from the normalized blend and steps along the average log until the step is below the
+//tolerance. Motors more than half a turn away from the mean don't have a unique log, so widely
+//spread rotations can converge to one of several means
+pub fn mean(motors: &[(Motor, Float)], tolerance: Float, max_iterations: usize) -> Option<Mean> {
+    let total: Float = motors.iter().map(|(_, w)| w).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut mean = Mean {
+        motor: blend(motors)?,
+        iterations: 0,
+        residual: Float::INFINITY,
+        converged: false,
+    };
+    while mean.iterations < max_iterations {
+        let inverse = mean.motor.inverse();
+        let step = motors
+            .iter()
+            .fold(Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0), |sum, (m, w)| {
+                sum + inverse.combine(*m).log() * *w
+            })
+            * (1.0 / total);
+        mean.motor = mean.motor.combine(Motor::exp(step)).normalized();
+        mean.iterations += 1;
+        mean.residual = step.weight_norm() + step.bulk_norm();
+        if mean.residual <= tolerance {
+            mean.converged = true;
+            break;
+        }
+    }
+    Some(mean)
+}
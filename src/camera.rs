@@ -0,0 +1,140 @@
+use crate::{collineation::Collineation, line::Line, motor::Motor, point::Point, Float};
+
+//A pinhole camera looking down -Z with +Y up in its own space. The pose moves the camera into
+//the world, and intrinsics are in pixels with the origin at the top left corner and Y pointing
+//down. Depth follows Collineation::perspective, running 0 to 1 from near to far, or 1 to 0 with
+//reverse Z
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Camera {
+    pub pose: Motor,
+    pub focal_x: Float,
+    pub focal_y: Float,
+    pub center_x: Float,
+    pub center_y: Float,
+    pub width: Float,
+    pub height: Float,
+    pub near: Float,
+    pub far: Float,
+    pub reverse_z: bool,
+}
+impl Camera {
+    //Square pixels with the principal point in the middle of the image
+    pub fn new(
+        pose: Motor,
+        fov_y: Float,
+        width: Float,
+        height: Float,
+        near: Float,
+        far: Float,
+    ) -> Self {
+        let focal = height * 0.5 / (fov_y * 0.5).tan();
+        Self::from_focal_lengths(
+            pose,
+            focal,
+            focal,
+            width * 0.5,
+            height * 0.5,
+            width,
+            height,
+            near,
+            far,
+        )
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_focal_lengths(
+        pose: Motor,
+        focal_x: Float,
+        focal_y: Float,
+        center_x: Float,
+        center_y: Float,
+        width: Float,
+        height: Float,
+        near: Float,
+        far: Float,
+    ) -> Self {
+        Self {
+            pose,
+            focal_x,
+            focal_y,
+            center_x,
+            center_y,
+            width,
+            height,
+            near,
+            far,
+            reverse_z: false,
+        }
+    }
+    pub fn with_reverse_z(mut self, reverse_z: bool) -> Self {
+        self.reverse_z = reverse_z;
+        self
+    }
+
+    pub fn aspect(&self) -> Float {
+        self.width / self.height
+    }
+    pub fn fov_y(&self) -> Float {
+        2.0 * (self.height * 0.5 / self.focal_y).atan()
+    }
+
+    //Moves world space into camera space
+    pub fn view_motor(&self) -> Motor {
+        self.pose.inverse()
+    }
+
+    //Pixel x, pixel y and depth. None for points behind the camera or in its plane. Points with a
+    //negative weight count as being on the opposite side
+    pub fn project(&self, p: Point) -> Option<[Float; 3]> {
+        let q = self.view_motor().transform(p);
+        let dist = -q.z;
+        if dist <= 0.0 {
+            return None;
+        }
+        let depth = self.far / (self.far - self.near) * (1.0 - self.near * q.w / dist);
+        Some([
+            self.center_x + self.focal_x * q.x / dist,
+            self.center_y - self.focal_y * q.y / dist,
+            if self.reverse_z { 1.0 - depth } else { depth },
+        ])
+    }
+    //The ray through a pixel, leaving the camera position in the viewing direction
+    pub fn unproject(&self, x: Float, y: Float) -> Line {
+        let dx = (x - self.center_x) / self.focal_x;
+        let dy = (self.center_y - y) / self.focal_y;
+        let ray = Line::new(dx, dy, -1.0, 0.0, 0.0, 0.0);
+        self.pose.transform_line(ray)
+    }
+
+    pub fn view_matrix(&self) -> Collineation {
+        Collineation::from(self.view_motor())
+    }
+    //Camera space to clip space
+    pub fn projection_matrix(&self) -> Collineation {
+        let range = 1.0 / (self.near - self.far);
+        let (depth_z, depth_w) = if self.reverse_z {
+            (-self.near * range, -self.near * self.far * range)
+        } else {
+            (self.far * range, self.near * self.far * range)
+        };
+        Collineation::from_rows([
+            [
+                2.0 * self.focal_x / self.width,
+                0.0,
+                1.0 - 2.0 * self.center_x / self.width,
+                0.0,
+            ],
+            [
+                0.0,
+                2.0 * self.focal_y / self.height,
+                2.0 * self.center_y / self.height - 1.0,
+                0.0,
+            ],
+            [0.0, 0.0, depth_z, depth_w],
+            [0.0, 0.0, -1.0, 0.0],
+        ])
+    }
+    pub fn view_projection_matrix(&self) -> Collineation {
+        self.view_matrix().combine(self.projection_matrix())
+    }
+}
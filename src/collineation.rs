@@ -0,0 +1,214 @@
+use crate::{batch::MotorMatrix, line::Line, motor::Motor, plane::Plane, point::Point, Float};
+
+//A general projective map, stored column-major like a GPU matrix. Points are transformed as
+//column vectors, planes by the inverse transpose and lines by the induced 6x6 map on their
+//Plücker coordinates, so joins and meets of transformed elements agree with transformed joins and
+//meets
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Collineation {
+    pub cols: [[Float; 4]; 4],
+}
+impl Default for Collineation {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+impl Collineation {
+    pub const IDENTITY: Self = Self::from_rows([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    pub const fn new(cols: [[Float; 4]; 4]) -> Self {
+        Self { cols }
+    }
+    pub const fn from_rows(rows: [[Float; 4]; 4]) -> Self {
+        let mut cols = [[0.0; 4]; 4];
+        let mut r = 0;
+        while r < 4 {
+            let mut c = 0;
+            while c < 4 {
+                cols[c][r] = rows[r][c];
+                c += 1;
+            }
+            r += 1;
+        }
+        Self { cols }
+    }
+
+    pub fn scale(x: Float, y: Float, z: Float) -> Self {
+        Self::from_rows([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+    //Each factor is how much one coordinate moves per unit of another, so xy adds xy * y to x
+    pub fn shear(xy: Float, xz: Float, yx: Float, yz: Float, zx: Float, zy: Float) -> Self {
+        Self::from_rows([
+            [1.0, xy, xz, 0.0],
+            [yx, 1.0, yz, 0.0],
+            [zx, zy, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+    //Right handed, looking down -Z, with depth mapped to 0 at near and 1 at far
+    pub fn perspective(fov_y: Float, aspect: Float, near: Float, far: Float) -> Self {
+        let f = 1.0 / (fov_y * 0.5).tan();
+        let range = 1.0 / (near - far);
+        Self::from_rows([
+            [f / aspect, 0.0, 0.0, 0.0],
+            [0.0, f, 0.0, 0.0],
+            [0.0, 0.0, far * range, near * far * range],
+            [0.0, 0.0, -1.0, 0.0],
+        ])
+    }
+    //Same conventions as perspective
+    pub fn orthographic(
+        left: Float,
+        right: Float,
+        bottom: Float,
+        top: Float,
+        near: Float,
+        far: Float,
+    ) -> Self {
+        let w = 1.0 / (right - left);
+        let h = 1.0 / (top - bottom);
+        let range = 1.0 / (near - far);
+        Self::from_rows([
+            [2.0 * w, 0.0, 0.0, -(right + left) * w],
+            [0.0, 2.0 * h, 0.0, -(top + bottom) * h],
+            [0.0, 0.0, range, near * range],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    #[inline]
+    fn at(&self, row: usize, col: usize) -> Float {
+        self.cols[col][row]
+    }
+
+    pub fn into_buffer(&self) -> [Float; 16] {
+        let c = &self.cols;
+        [
+            c[0][0], c[0][1], c[0][2], c[0][3], c[1][0], c[1][1], c[1][2], c[1][3], c[2][0],
+            c[2][1], c[2][2], c[2][3], c[3][0], c[3][1], c[3][2], c[3][3],
+        ]
+    }
+    #[allow(clippy::unnecessary_cast)]
+    pub fn into_buffer_32(&self) -> [f32; 16] {
+        self.into_buffer().map(|v| v as f32)
+    }
+
+    //Applies self, then other
+    pub fn combine(&self, other: Collineation) -> Self {
+        let mut cols = [[0.0; 4]; 4];
+        for (c, col) in cols.iter_mut().enumerate() {
+            for (r, v) in col.iter_mut().enumerate() {
+                *v = (0..4).map(|k| other.at(r, k) * self.at(k, c)).sum();
+            }
+        }
+        Self::new(cols)
+    }
+    pub fn combine_motor(&self, other: Motor) -> Self {
+        self.combine(Self::from(other))
+    }
+
+    pub fn determinant(&self) -> Float {
+        let cof = self.cofactors();
+        (0..4).map(|c| self.at(0, c) * cof[0][c]).sum()
+    }
+    //Not finite for singular maps, such as a projection onto a plane
+    pub fn inverse(&self) -> Self {
+        let cof = self.cofactors();
+        let det: Float = (0..4).map(|c| self.at(0, c) * cof[0][c]).sum();
+        let inv_det = 1.0 / det;
+        //The inverse is the transposed cofactor matrix over the determinant, and cols[c][r]
+        //holds row r, column c
+        let mut cols = [[0.0; 4]; 4];
+        for (c, col) in cols.iter_mut().enumerate() {
+            for (r, v) in col.iter_mut().enumerate() {
+                *v = cof[c][r] * inv_det;
+            }
+        }
+        Self::new(cols)
+    }
+
+    pub fn transform(&self, p: Point) -> Point {
+        let v = [p.x, p.y, p.z, p.w];
+        let row = |r: usize| (0..4).map(|k| self.at(r, k) * v[k]).sum();
+        Point::new(row(0), row(1), row(2), row(3))
+    }
+    //Inverts the map on every call. To transform many planes, invert once and use
+    //transform_plane_by_inverse
+    pub fn transform_plane(&self, p: Plane) -> Plane {
+        self.inverse().transform_plane_by_inverse(p)
+    }
+    //Transforms a plane by the map whose inverse is self
+    pub fn transform_plane_by_inverse(&self, p: Plane) -> Plane {
+        let v = [p.x, p.y, p.z, p.w];
+        let col = |c: usize| (0..4).map(|k| self.at(k, c) * v[k]).sum();
+        Plane::new(col(0), col(1), col(2), col(3))
+    }
+    pub fn transform_line(&self, l: Line) -> Line {
+        let m = self.line_matrix();
+        let v = [l.vx, l.vy, l.vz, l.mx, l.my, l.mz];
+        let row = |r: usize| (0..6).map(|k| m[r][k] * v[k]).sum();
+        Line::new(row(0), row(1), row(2), row(3), row(4), row(5))
+    }
+
+    //The induced map on Plücker coordinates, row-major in Line's component order
+    pub fn line_matrix(&self) -> [[Float; 6]; 6] {
+        //The coordinate pairs each line component is a 2x2 minor of, matching Point::join
+        const PAIRS: [(usize, usize); 6] = [(3, 0), (3, 1), (3, 2), (1, 2), (2, 0), (0, 1)];
+        PAIRS.map(|(i, j)| {
+            PAIRS.map(|(k, l)| self.at(i, k) * self.at(j, l) - self.at(i, l) * self.at(j, k))
+        })
+    }
+
+    //cof[r][c] is the cofactor of row r, column c
+    fn cofactors(&self) -> [[Float; 4]; 4] {
+        let others = |i: usize| match i {
+            0 => [1, 2, 3],
+            1 => [0, 2, 3],
+            2 => [0, 1, 3],
+            _ => [0, 1, 2],
+        };
+        let mut cof = [[0.0; 4]; 4];
+        for (r, row) in cof.iter_mut().enumerate() {
+            for (c, v) in row.iter_mut().enumerate() {
+                let (rows, cols) = (others(r), others(c));
+                let m = |i: usize, j: usize| self.at(rows[i], cols[j]);
+                let minor = m(0, 0) * (m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1))
+                    - m(0, 1) * (m(1, 0) * m(2, 2) - m(1, 2) * m(2, 0))
+                    + m(0, 2) * (m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0));
+                *v = if (r + c) % 2 == 0 { minor } else { -minor };
+            }
+        }
+        cof
+    }
+}
+impl From<Motor> for Collineation {
+    fn from(value: Motor) -> Self {
+        let m = MotorMatrix::new(&value);
+        let (r, t) = (m.r, m.t);
+        Self::from_rows([
+            [r[0][0], r[0][1], r[0][2], t[0]],
+            [r[1][0], r[1][1], r[1][2], t[1]],
+            [r[2][0], r[2][1], r[2][2], t[2]],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+impl Motor {
+    pub fn combine_collineation(&self, other: Collineation) -> Collineation {
+        Collineation::from(*self).combine(other)
+    }
+}
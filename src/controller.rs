@@ -0,0 +1,149 @@
+use crate::{direction::Direction, motor::Motor, point::Point, Float};
+
+#[cfg(not(feature = "f64"))]
+use std::f32::consts as float_consts;
+#[cfg(feature = "f64")]
+use std::f64::consts as float_consts;
+
+//Keeps pitch just short of straight up or down, where yaw stops being well defined
+const PITCH_LIMIT: Float = float_consts::FRAC_PI_2 - 0.001;
+
+//Poses follow the Camera conventions, moving the camera into the world with the view down -Z.
+//Orbit and fly poses are rebuilt from their parameters, so they never drift from unit motors
+
+//Maps mouse positions onto a virtual sphere in front of the viewport. Positions are in pixels with
+//the origin at the top left, and rotations are in view space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Arcball {
+    pub width: Float,
+    pub height: Float,
+    //Sphere radius as a fraction of half the smaller viewport side
+    pub radius: Float,
+}
+impl Arcball {
+    pub fn new(width: Float, height: Float) -> Self {
+        Self {
+            width,
+            height,
+            radius: 1.0,
+        }
+    }
+
+    //The point on the sphere under a pixel. Outside the sphere it continues onto a hyperbolic
+    //sheet, so dragging past the edge keeps rotating smoothly
+    pub fn sphere_point(&self, x: Float, y: Float) -> Direction {
+        let scale = 2.0 / (self.width.min(self.height) * self.radius);
+        let px = (x - self.width * 0.5) * scale;
+        let py = (self.height * 0.5 - y) * scale;
+        let d2 = px * px + py * py;
+        let pz = if d2 <= 0.5 {
+            (1.0 - d2).sqrt()
+        } else {
+            0.5 / d2.sqrt()
+        };
+        Direction::new(px, py, pz).normalized()
+    }
+    //The rotation carrying the sphere point under from to the one under to
+    pub fn rotation(&self, from: (Float, Float), to: (Float, Float)) -> Motor {
+        let a = self.sphere_point(from.0, from.1);
+        let b = self.sphere_point(to.0, to.1);
+        let axis = a.cross(b);
+        Motor::new(axis.x, axis.y, axis.z, 1.0 + a.dot(b), 0.0, 0.0, 0.0, 0.0).normalized()
+    }
+    //Applies a drag to an orientation, renormalizing so that long interactions stay rigid
+    pub fn drag(&self, orientation: Motor, from: (Float, Float), to: (Float, Float)) -> Motor {
+        orientation.combine(self.rotation(from, to)).normalized()
+    }
+}
+
+//Circles a target. Positive pitch raises the camera above the target
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitCamera {
+    pub target: Point,
+    pub distance: Float,
+    pub yaw: Float,
+    pub pitch: Float,
+    pub min_distance: Float,
+}
+impl OrbitCamera {
+    pub fn new(target: Point, distance: Float) -> Self {
+        Self {
+            target,
+            distance,
+            yaw: 0.0,
+            pitch: 0.0,
+            min_distance: 0.01,
+        }
+    }
+
+    pub fn rotate(&mut self, yaw: Float, pitch: Float) {
+        self.yaw = wrap_angle(self.yaw + yaw);
+        self.pitch = (self.pitch + pitch).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+    //Moves the target across the view. Offsets are scaled by the distance, so a drag moves the
+    //scene by about the same amount on screen at any zoom
+    pub fn pan(&mut self, right: Float, up: Float) {
+        let rotation = self.rotation();
+        let offset = rotation.transform_direction(Direction::RIGHT) * (right * self.distance)
+            + rotation.transform_direction(Direction::UP) * (up * self.distance);
+        self.target = self.target.scaled() + offset;
+    }
+    //Factors below 1 move closer
+    pub fn dolly(&mut self, factor: Float) {
+        self.distance = (self.distance * factor).max(self.min_distance);
+    }
+
+    pub fn pose(&self) -> Motor {
+        let target = self.target.scaled();
+        let back = self.rotation().transform_direction(Direction::BACK) * self.distance;
+        Motor::from_euler_pos_and_rot(
+            target.x + back.x,
+            target.y + back.y,
+            target.z + back.z,
+            -self.pitch,
+            self.yaw,
+            0.0,
+        )
+    }
+    fn rotation(&self) -> Motor {
+        Motor::from_euler_angles(-self.pitch, self.yaw, 0.0)
+    }
+}
+
+//Free flight. Positive pitch looks up
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlyCamera {
+    pub position: Point,
+    pub yaw: Float,
+    pub pitch: Float,
+}
+impl FlyCamera {
+    pub fn new(position: Point) -> Self {
+        Self {
+            position,
+            yaw: 0.0,
+            pitch: 0.0,
+        }
+    }
+
+    pub fn rotate(&mut self, yaw: Float, pitch: Float) {
+        self.yaw = wrap_angle(self.yaw + yaw);
+        self.pitch = (self.pitch + pitch).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+    //Moves along the camera's own axes
+    pub fn move_local(&mut self, forward: Float, right: Float, up: Float) {
+        let offset = self
+            .pose()
+            .transform_direction(Direction::new(right, up, -forward));
+        self.position = self.position.scaled() + offset;
+    }
+
+    pub fn pose(&self) -> Motor {
+        let p = self.position.scaled();
+        Motor::from_euler_pos_and_rot(p.x, p.y, p.z, self.pitch, self.yaw, 0.0)
+    }
+}
+
+fn wrap_angle(a: Float) -> Float {
+    (a + float_consts::PI).rem_euclid(float_consts::TAU) - float_consts::PI
+}
@@ -0,0 +1,179 @@
+use crate::{
+    direction::Direction, line::Line, motor::Motor, plane::Plane, point::Point, rotor::Rotor, Float,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    PosX,
+    NegX,
+    PosY,
+    NegY,
+    PosZ,
+    NegZ,
+}
+impl Axis {
+    pub const fn index(&self) -> usize {
+        match self {
+            Axis::PosX | Axis::NegX => 0,
+            Axis::PosY | Axis::NegY => 1,
+            Axis::PosZ | Axis::NegZ => 2,
+        }
+    }
+    pub const fn sign(&self) -> Float {
+        match self {
+            Axis::PosX | Axis::PosY | Axis::PosZ => 1.0,
+            Axis::NegX | Axis::NegY | Axis::NegZ => -1.0,
+        }
+    }
+    pub fn direction(&self) -> Direction {
+        let mut d = [0.0; 3];
+        d[self.index()] = self.sign();
+        Direction::new(d[0], d[1], d[2])
+    }
+}
+
+//Which axis points right, up and forward. The crate's own constants such as Direction::UP follow
+//CoordinateSystem::NATIVE
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoordinateSystem {
+    right: Axis,
+    up: Axis,
+    forward: Axis,
+}
+impl CoordinateSystem {
+    //Right handed Y up with -Z forward, shared with OpenGL and glTF
+    pub const NATIVE: Self = Self::new_unchecked(Axis::PosX, Axis::PosY, Axis::NegZ);
+    pub const BLENDER: Self = Self::new_unchecked(Axis::PosX, Axis::PosZ, Axis::PosY);
+    pub const UNITY: Self = Self::new_unchecked(Axis::PosX, Axis::PosY, Axis::PosZ);
+    pub const UNREAL: Self = Self::new_unchecked(Axis::PosY, Axis::PosZ, Axis::PosX);
+    //REP 103, X forward, Y left and Z up
+    pub const ROS: Self = Self::new_unchecked(Axis::NegY, Axis::PosZ, Axis::PosX);
+
+    //None if two directions share an axis
+    pub fn new(right: Axis, up: Axis, forward: Axis) -> Option<Self> {
+        let (r, u, f) = (right.index(), up.index(), forward.index());
+        (r != u && u != f && f != r).then_some(Self::new_unchecked(right, up, forward))
+    }
+    const fn new_unchecked(right: Axis, up: Axis, forward: Axis) -> Self {
+        Self { right, up, forward }
+    }
+
+    pub fn right(&self) -> Direction {
+        self.right.direction()
+    }
+    pub fn left(&self) -> Direction {
+        -self.right.direction()
+    }
+    pub fn up(&self) -> Direction {
+        self.up.direction()
+    }
+    pub fn down(&self) -> Direction {
+        -self.up.direction()
+    }
+    pub fn forward(&self) -> Direction {
+        self.forward.direction()
+    }
+    pub fn back(&self) -> Direction {
+        -self.forward.direction()
+    }
+    //Right handed when right, up and back follow the right hand rule
+    pub fn is_right_handed(&self) -> bool {
+        self.right().cross(self.up()).dot(self.back()) > 0.0
+    }
+
+    pub fn conversion_to(&self, target: CoordinateSystem) -> Conversion {
+        let mut source = [0; 3];
+        let mut sign = [0.0; 3];
+        for (a, b) in [
+            (self.right, target.right),
+            (self.up, target.up),
+            (self.forward, target.forward),
+        ] {
+            source[b.index()] = a.index();
+            sign[b.index()] = a.sign() * b.sign();
+        }
+        let det = if self.is_right_handed() == target.is_right_handed() {
+            1.0
+        } else {
+            -1.0
+        };
+        Conversion { source, sign, det }
+    }
+}
+impl Default for CoordinateSystem {
+    fn default() -> Self {
+        Self::NATIVE
+    }
+}
+
+//A change of basis between two coordinate systems. Only components are permuted and negated, so
+//conversions are exact and round trip bit for bit. Pseudo vectors such as rotation axes and line
+//moments pick up an extra sign when the handedness changes, so rotations keep turning the same way
+//in space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conversion {
+    //Component i of the result is sign[i] times component source[i] of the input
+    source: [usize; 3],
+    sign: [Float; 3],
+    det: Float,
+}
+impl Conversion {
+    pub const IDENTITY: Self = Self {
+        source: [0, 1, 2],
+        sign: [1.0; 3],
+        det: 1.0,
+    };
+
+    pub fn inverse(&self) -> Self {
+        let mut source = [0; 3];
+        let mut sign = [0.0; 3];
+        for i in 0..3 {
+            source[self.source[i]] = i;
+            sign[self.source[i]] = self.sign[i];
+        }
+        Self {
+            source,
+            sign,
+            det: self.det,
+        }
+    }
+    pub fn flips_handedness(&self) -> bool {
+        self.det < 0.0
+    }
+
+    fn apply(&self, v: [Float; 3]) -> [Float; 3] {
+        [0, 1, 2].map(|i| self.sign[i] * v[self.source[i]])
+    }
+    fn apply_pseudo(&self, v: [Float; 3]) -> [Float; 3] {
+        self.apply(v).map(|c| c * self.det)
+    }
+
+    pub fn direction(&self, d: Direction) -> Direction {
+        let [x, y, z] = self.apply([d.x, d.y, d.z]);
+        Direction::new(x, y, z)
+    }
+    pub fn point(&self, p: Point) -> Point {
+        let [x, y, z] = self.apply([p.x, p.y, p.z]);
+        Point::new(x, y, z, p.w)
+    }
+    pub fn plane(&self, p: Plane) -> Plane {
+        let [x, y, z] = self.apply([p.x, p.y, p.z]);
+        Plane::new(x, y, z, p.w)
+    }
+    pub fn line(&self, l: Line) -> Line {
+        let [vx, vy, vz] = self.apply([l.vx, l.vy, l.vz]);
+        let [mx, my, mz] = self.apply_pseudo([l.mx, l.my, l.mz]);
+        Line::new(vx, vy, vz, mx, my, mz)
+    }
+    pub fn rotor(&self, r: Rotor) -> Rotor {
+        let [vx, vy, vz] = self.apply_pseudo([r.vx, r.vy, r.vz]);
+        Rotor::new(vx, vy, vz, r.vw)
+    }
+    pub fn motor(&self, m: Motor) -> Motor {
+        let [vx, vy, vz] = self.apply_pseudo([m.vx, m.vy, m.vz]);
+        let [mx, my, mz] = self.apply([m.mx, m.my, m.mz]);
+        Motor::new(vx, vy, vz, m.vw, mx, my, mz, m.mw * self.det)
+    }
+}
@@ -0,0 +1,102 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{point::Point, Float};
+
+//An ideal point (w = 0). Unaffected by translation, with a regular Euclidean length
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Direction {
+    pub x: Float,
+    pub y: Float,
+    pub z: Float,
+}
+impl Direction {
+    pub const ZERO: Direction = Direction::new(0.0, 0.0, 0.0);
+    pub const UP: Direction = Direction::new(0.0, 1.0, 0.0);
+    pub const DOWN: Direction = Direction::new(0.0, -1.0, 0.0);
+    pub const LEFT: Direction = Direction::new(-1.0, 0.0, 0.0);
+    pub const RIGHT: Direction = Direction::new(1.0, 0.0, 0.0);
+    pub const FORWARD: Direction = Direction::new(0.0, 0.0, -1.0);
+    pub const BACK: Direction = Direction::new(0.0, 0.0, 1.0);
+
+    pub const fn new(x: Float, y: Float, z: Float) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn into_buffer(&self) -> [Float; 3] {
+        [self.x, self.y, self.z]
+    }
+    #[allow(clippy::unnecessary_cast)]
+    pub fn into_buffer_32(&self) -> [f32; 3] {
+        [self.x as f32, self.y as f32, self.z as f32]
+    }
+
+    pub fn is_close(&self, other: Direction) -> bool {
+        ((self.x - other.x).abs() < 0.01)
+            && ((self.y - other.y).abs() < 0.01)
+            && ((self.z - other.z).abs() < 0.01)
+    }
+
+    pub fn dot(&self, rhs: Direction) -> Float {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+    pub fn cross(&self, rhs: Direction) -> Direction {
+        Direction::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    pub fn magnitude_squared(&self) -> Float {
+        self.dot(*self)
+    }
+    pub fn magnitude(&self) -> Float {
+        self.magnitude_squared().sqrt()
+    }
+    pub fn normalized(&self) -> Self {
+        *self * (1.0 / self.magnitude())
+    }
+}
+impl From<Direction> for Point {
+    fn from(value: Direction) -> Self {
+        Point::from_direction(value.x, value.y, value.z)
+    }
+}
+impl Add<Direction> for Direction {
+    type Output = Self;
+    fn add(self, rhs: Direction) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+impl Sub<Direction> for Direction {
+    type Output = Self;
+    fn sub(self, rhs: Direction) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+impl Neg for Direction {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+impl Mul<Float> for Direction {
+    type Output = Self;
+    fn mul(self, rhs: Float) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+impl Add<Direction> for Point {
+    type Output = Self;
+    fn add(self, rhs: Direction) -> Self::Output {
+        Self {
+            x: self.x + rhs.x * self.w,
+            y: self.y + rhs.y * self.w,
+            z: self.z + rhs.z * self.w,
+            w: self.w,
+        }
+    }
+}
@@ -0,0 +1,464 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    line::Line,
+    motor::{motor_product, Motor},
+    plane::Plane,
+    point::Point,
+    Float,
+};
+
+//Signed 32.32 fixed-point number. Everything is integer arithmetic that wraps on overflow, and
+//division by zero saturates, so results are bit-identical on every platform and in both debug and
+//release builds without ever panicking
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
+pub struct Fixed(pub i64);
+impl Fixed {
+    pub const FRAC_BITS: u32 = 32;
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(1 << 32);
+    pub const HALF: Fixed = Fixed(1 << 31);
+    pub const TWO: Fixed = Fixed(2 << 32);
+    pub const PI: Fixed = Fixed(0x3_243F_6A89);
+    pub const FRAC_PI_2: Fixed = Fixed(0x1_921F_B544);
+    pub const TAU: Fixed = Fixed(0x6_487E_D511);
+
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+    pub const fn from_int(v: i32) -> Self {
+        Self((v as i64) << 32)
+    }
+    //Rounds to the nearest representable value
+    pub fn from_f64(v: f64) -> Self {
+        Self((v * (1u64 << 32) as f64).round() as i64)
+    }
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << 32) as f64
+    }
+    #[allow(clippy::unnecessary_cast)]
+    pub fn from_float(v: Float) -> Self {
+        Self::from_f64(v as f64)
+    }
+    #[allow(clippy::unnecessary_cast)]
+    pub fn to_float(self) -> Float {
+        self.to_f64() as Float
+    }
+
+    pub const fn abs(self) -> Self {
+        Self(self.0.wrapping_abs())
+    }
+    const fn div_int(self, d: i64) -> Self {
+        Self(self.0 / d)
+    }
+
+    //Zero for negative input
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+        let n = (self.0 as u128) << 32;
+        let mut root = 0u128;
+        let mut bit = 1u128 << (126 - (n.leading_zeros() & !1));
+        let mut rem = n;
+        while bit != 0 {
+            if rem >= root + bit {
+                rem -= root + bit;
+                root = (root >> 1) + bit;
+            } else {
+                root >>= 1;
+            }
+            bit >>= 2;
+        }
+        Self(root as i64)
+    }
+
+    pub fn sin(self) -> Self {
+        //Reduce to [-pi, pi], then fold to [-pi/2, pi/2]
+        let mut x = Self((self.0.wrapping_add(Self::PI.0)).rem_euclid(Self::TAU.0) - Self::PI.0);
+        if x > Self::FRAC_PI_2 {
+            x = Self::PI - x;
+        } else if x < -Self::FRAC_PI_2 {
+            x = -Self::PI - x;
+        }
+
+        //Taylor series in Horner form, accurate to a couple of ulps on this interval
+        let x2 = x * x;
+        let mut r = Self::ONE;
+        for d in [210, 156, 110, 72, 42, 20, 6] {
+            r = Self::ONE - (x2 * r).div_int(d);
+        }
+        x * r
+    }
+    pub fn cos(self) -> Self {
+        (self + Self::FRAC_PI_2).sin()
+    }
+}
+impl Add for Fixed {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+}
+impl Sub for Fixed {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+}
+impl Mul for Fixed {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(((self.0 as i128 * rhs.0 as i128 + (1 << 31)) >> 32) as i64)
+    }
+}
+impl Div for Fixed {
+    type Output = Self;
+    //Dividing by zero saturates to the largest value with the sign of the dividend, or zero for
+    //zero over zero
+    fn div(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            return Self(self.0.signum() * i64::MAX);
+        }
+        Self((((self.0 as i128) << 32) / rhs.0 as i128) as i64)
+    }
+}
+impl Neg for Fixed {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct FixedPoint {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub z: Fixed,
+    pub w: Fixed,
+}
+impl Default for FixedPoint {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+impl FixedPoint {
+    pub const ZERO: FixedPoint = FixedPoint::new(Fixed::ZERO, Fixed::ZERO, Fixed::ZERO, Fixed::ONE);
+
+    pub const fn new(x: Fixed, y: Fixed, z: Fixed, w: Fixed) -> Self {
+        Self { x, y, z, w }
+    }
+    pub const fn from_position(x: Fixed, y: Fixed, z: Fixed) -> Self {
+        Self::new(x, y, z, Fixed::ONE)
+    }
+
+    pub fn join(&self, rhs: FixedPoint) -> FixedLine {
+        FixedLine::new(
+            self.w * rhs.x - self.x * rhs.w,
+            self.w * rhs.y - self.y * rhs.w,
+            self.w * rhs.z - self.z * rhs.w,
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+    pub fn dot(&self, rhs: FixedPoint) -> Fixed {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+}
+impl From<Point> for FixedPoint {
+    fn from(p: Point) -> Self {
+        Self::new(
+            Fixed::from_float(p.x),
+            Fixed::from_float(p.y),
+            Fixed::from_float(p.z),
+            Fixed::from_float(p.w),
+        )
+    }
+}
+impl From<FixedPoint> for Point {
+    fn from(p: FixedPoint) -> Self {
+        Point::new(
+            p.x.to_float(),
+            p.y.to_float(),
+            p.z.to_float(),
+            p.w.to_float(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct FixedLine {
+    pub vx: Fixed,
+    pub vy: Fixed,
+    pub vz: Fixed,
+    pub mx: Fixed,
+    pub my: Fixed,
+    pub mz: Fixed,
+}
+impl FixedLine {
+    pub const fn new(vx: Fixed, vy: Fixed, vz: Fixed, mx: Fixed, my: Fixed, mz: Fixed) -> Self {
+        Self {
+            vx,
+            vy,
+            vz,
+            mx,
+            my,
+            mz,
+        }
+    }
+
+    pub fn join(&self, rhs: FixedPoint) -> FixedPlane {
+        FixedPlane::new(
+            self.vy * rhs.z - self.vz * rhs.y + self.mx * rhs.w,
+            self.vz * rhs.x - self.vx * rhs.z + self.my * rhs.w,
+            self.vx * rhs.y - self.vy * rhs.x + self.mz * rhs.w,
+            -(self.mx * rhs.x + self.my * rhs.y + self.mz * rhs.z),
+        )
+    }
+}
+impl From<Line> for FixedLine {
+    fn from(l: Line) -> Self {
+        Self::new(
+            Fixed::from_float(l.vx),
+            Fixed::from_float(l.vy),
+            Fixed::from_float(l.vz),
+            Fixed::from_float(l.mx),
+            Fixed::from_float(l.my),
+            Fixed::from_float(l.mz),
+        )
+    }
+}
+impl From<FixedLine> for Line {
+    fn from(l: FixedLine) -> Self {
+        Line::new(
+            l.vx.to_float(),
+            l.vy.to_float(),
+            l.vz.to_float(),
+            l.mx.to_float(),
+            l.my.to_float(),
+            l.mz.to_float(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct FixedPlane {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub z: Fixed,
+    pub w: Fixed,
+}
+impl FixedPlane {
+    pub const fn new(x: Fixed, y: Fixed, z: Fixed, w: Fixed) -> Self {
+        Self { x, y, z, w }
+    }
+
+    //Signed distance scaled by the weight norm and the point weight
+    pub fn dot_point(&self, p: FixedPoint) -> Fixed {
+        self.x * p.x + self.y * p.y + self.z * p.z + self.w * p.w
+    }
+}
+impl From<Plane> for FixedPlane {
+    fn from(p: Plane) -> Self {
+        Self::new(
+            Fixed::from_float(p.x),
+            Fixed::from_float(p.y),
+            Fixed::from_float(p.z),
+            Fixed::from_float(p.w),
+        )
+    }
+}
+impl From<FixedPlane> for Plane {
+    fn from(p: FixedPlane) -> Self {
+        Plane::new(
+            p.x.to_float(),
+            p.y.to_float(),
+            p.z.to_float(),
+            p.w.to_float(),
+        )
+    }
+}
+
+//Same layout and conventions as Motor. Transform and inverse assume a unit motor, use normalized
+//to restore that after long chains of combines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct FixedMotor {
+    pub vx: Fixed,
+    pub vy: Fixed,
+    pub vz: Fixed,
+    pub vw: Fixed,
+    pub mx: Fixed,
+    pub my: Fixed,
+    pub mz: Fixed,
+    pub mw: Fixed,
+}
+impl Default for FixedMotor {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+impl FixedMotor {
+    pub const IDENTITY: Self = Self::new(
+        Fixed::ZERO,
+        Fixed::ZERO,
+        Fixed::ZERO,
+        Fixed::ONE,
+        Fixed::ZERO,
+        Fixed::ZERO,
+        Fixed::ZERO,
+        Fixed::ZERO,
+    );
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        vx: Fixed,
+        vy: Fixed,
+        vz: Fixed,
+        vw: Fixed,
+        mx: Fixed,
+        my: Fixed,
+        mz: Fixed,
+        mw: Fixed,
+    ) -> Self {
+        Self {
+            vx,
+            vy,
+            vz,
+            vw,
+            mx,
+            my,
+            mz,
+            mw,
+        }
+    }
+    pub fn from_translation(x: Fixed, y: Fixed, z: Fixed) -> Self {
+        Self::new(
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::ONE,
+            x * Fixed::HALF,
+            y * Fixed::HALF,
+            z * Fixed::HALF,
+            Fixed::ZERO,
+        )
+    }
+    pub fn from_rotation_around_axis(
+        axis_x: Fixed,
+        axis_y: Fixed,
+        axis_z: Fixed,
+        angle: Fixed,
+    ) -> Self {
+        let angle = angle * Fixed::HALF;
+        let sin_angle = angle.sin();
+        let cos_angle = angle.cos();
+
+        Self::new(
+            axis_x * sin_angle,
+            axis_y * sin_angle,
+            axis_z * sin_angle,
+            cos_angle,
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::ZERO,
+        )
+    }
+
+    pub fn combine(&self, other: FixedMotor) -> Self {
+        motor_product!(Self::new, self, other)
+    }
+    pub fn transform(&self, p: FixedPoint) -> FixedPoint {
+        let ax = self.vy * p.z - self.vz * p.y + p.w * self.mx;
+        let ay = self.vz * p.x - self.vx * p.z + p.w * self.my;
+        let az = self.vx * p.y - self.vy * p.x + p.w * self.mz;
+
+        FixedPoint::new(
+            p.x + Fixed::TWO
+                * (self.vw * ax + (self.vy * az - self.vz * ay) - self.mw * p.w * self.vx),
+            p.y + Fixed::TWO
+                * (self.vw * ay + (self.vz * ax - self.vx * az) - self.mw * p.w * self.vy),
+            p.z + Fixed::TWO
+                * (self.vw * az + (self.vx * ay - self.vy * ax) - self.mw * p.w * self.vz),
+            p.w,
+        )
+    }
+    pub fn inverse(&self) -> Self {
+        Self::new(
+            -self.vx, -self.vy, -self.vz, self.vw, -self.mx, -self.my, -self.mz, self.mw,
+        )
+    }
+
+    //A zero motor stays zero, since division by zero saturates and the numerators are all zero
+    pub fn normalized(&self) -> Self {
+        let norm =
+            (self.vx * self.vx + self.vy * self.vy + self.vz * self.vz + self.vw * self.vw).sqrt();
+        let vx = self.vx / norm;
+        let vy = self.vy / norm;
+        let vz = self.vz / norm;
+        let vw = self.vw / norm;
+        let mx = self.mx / norm;
+        let my = self.my / norm;
+        let mz = self.mz / norm;
+        let mw = self.mw / norm;
+
+        let constraint = vx * mx + vy * my + vz * mz + vw * mw;
+
+        Self::new(
+            vx,
+            vy,
+            vz,
+            vw,
+            mx - constraint * vx,
+            my - constraint * vy,
+            mz - constraint * vz,
+            mw - constraint * vw,
+        )
+    }
+}
+impl From<Motor> for FixedMotor {
+    fn from(m: Motor) -> Self {
+        Self::new(
+            Fixed::from_float(m.vx),
+            Fixed::from_float(m.vy),
+            Fixed::from_float(m.vz),
+            Fixed::from_float(m.vw),
+            Fixed::from_float(m.mx),
+            Fixed::from_float(m.my),
+            Fixed::from_float(m.mz),
+            Fixed::from_float(m.mw),
+        )
+    }
+}
+impl From<FixedMotor> for Motor {
+    fn from(m: FixedMotor) -> Self {
+        Motor::new(
+            m.vx.to_float(),
+            m.vy.to_float(),
+            m.vz.to_float(),
+            m.vw.to_float(),
+            m.mx.to_float(),
+            m.my.to_float(),
+            m.mz.to_float(),
+            m.mw.to_float(),
+        )
+    }
+}
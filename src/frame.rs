@@ -0,0 +1,164 @@
+use std::{fmt, marker::PhantomData};
+
+use crate::{direction::Direction, line::Line, motor::Motor, plane::Plane, point::Point};
+
+//Opt in tags for the space a value lives in. Any type can be used as a frame, these are only the
+//common ones
+pub enum World {}
+pub enum Local {}
+
+//A value tagged with the frame it is expressed in. Tags only exist at compile time, so a framed
+//value has the same layout as the value itself
+#[repr(transparent)]
+pub struct Framed<T, F> {
+    pub value: T,
+    frame: PhantomData<fn() -> F>,
+}
+impl<T, F> Framed<T, F> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            frame: PhantomData,
+        }
+    }
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+    //Keeps the tag for a value computed from this one, such as an offset point
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Framed<U, F> {
+        Framed::new(f(self.value))
+    }
+}
+impl<T: Clone, F> Clone for Framed<T, F> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+impl<T: Copy, F> Copy for Framed<T, F> {}
+impl<T: PartialEq, F> PartialEq for Framed<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+impl<T: fmt::Debug, F> fmt::Debug for Framed<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Framed")
+            .field(&std::any::type_name::<F>())
+            .field(&self.value)
+            .finish()
+    }
+}
+
+macro_rules! impl_into_untyped {
+    ($($t:ty),*) => {
+        $(
+            impl<F> From<Framed<$t, F>> for $t {
+                fn from(value: Framed<$t, F>) -> Self {
+                    value.value
+                }
+            }
+        )*
+    };
+}
+impl_into_untyped!(Point, Direction, Line, Plane);
+
+//A motor moving values from frame A into frame B. Following Motor::combine, a.combine(b) applies a
+//first, so it only compiles when a ends in the frame b starts from
+#[repr(transparent)]
+pub struct FrameMotor<A, B> {
+    pub motor: Motor,
+    frames: PhantomData<fn(A) -> B>,
+}
+impl<A> FrameMotor<A, A> {
+    pub const IDENTITY: Self = Self::new(Motor::IDENTITY);
+}
+impl<A, B> FrameMotor<A, B> {
+    pub const fn new(motor: Motor) -> Self {
+        Self {
+            motor,
+            frames: PhantomData,
+        }
+    }
+    pub fn into_inner(self) -> Motor {
+        self.motor
+    }
+
+    pub fn combine<C>(&self, other: FrameMotor<B, C>) -> FrameMotor<A, C> {
+        FrameMotor::new(self.motor.combine(other.motor))
+    }
+    pub fn inverse(&self) -> FrameMotor<B, A> {
+        FrameMotor::new(self.motor.inverse())
+    }
+    pub fn normalized(&self) -> Self {
+        Self::new(self.motor.normalized())
+    }
+
+    pub fn transform(&self, p: Framed<Point, A>) -> Framed<Point, B> {
+        Framed::new(self.motor.transform(p.value))
+    }
+    pub fn transform_direction(&self, d: Framed<Direction, A>) -> Framed<Direction, B> {
+        Framed::new(self.motor.transform_direction(d.value))
+    }
+    pub fn transform_line(&self, l: Framed<Line, A>) -> Framed<Line, B> {
+        Framed::new(self.motor.transform_line(l.value))
+    }
+    pub fn transform_plane(&self, p: Framed<Plane, A>) -> Framed<Plane, B> {
+        Framed::new(self.motor.transform_plane(p.value))
+    }
+}
+impl<A, B> Clone for FrameMotor<A, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<A, B> Copy for FrameMotor<A, B> {}
+impl<A, B> PartialEq for FrameMotor<A, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.motor == other.motor
+    }
+}
+impl<A, B> fmt::Debug for FrameMotor<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FrameMotor")
+            .field(&std::any::type_name::<A>())
+            .field(&std::any::type_name::<B>())
+            .field(&self.motor)
+            .finish()
+    }
+}
+impl<A, B> From<FrameMotor<A, B>> for Motor {
+    fn from(value: FrameMotor<A, B>) -> Self {
+        value.motor
+    }
+}
+
+//Mismatched frames are compile errors, checked by these doctests
+#[cfg(doctest)]
+mod frame_checks {
+    /// ```
+    /// use hydrolox_pga3d::{frame::*, motor::Motor, point::Point};
+    /// enum Camera {}
+    /// let to_world = FrameMotor::<Local, World>::new(Motor::IDENTITY);
+    /// let to_camera = FrameMotor::<World, Camera>::new(Motor::IDENTITY);
+    /// let chained: FrameMotor<Local, Camera> = to_world.combine(to_camera);
+    /// let p: Framed<Point, Camera> = chained.transform(Framed::<_, Local>::new(Point::ZERO));
+    /// ```
+    struct MatchingFrames;
+
+    /// ```compile_fail
+    /// use hydrolox_pga3d::{frame::*, motor::Motor};
+    /// enum Camera {}
+    /// enum Screen {}
+    /// let to_world = FrameMotor::<Local, World>::new(Motor::IDENTITY);
+    /// let unrelated = FrameMotor::<Camera, Screen>::new(Motor::IDENTITY);
+    /// to_world.combine(unrelated);
+    /// ```
+    struct CombineMismatch;
+
+    /// ```compile_fail
+    /// use hydrolox_pga3d::{frame::*, motor::Motor, point::Point};
+    /// let to_world = FrameMotor::<Local, World>::new(Motor::IDENTITY);
+    /// to_world.transform(Framed::<_, World>::new(Point::ZERO));
+    /// ```
+    struct TransformMismatch;
+}
@@ -0,0 +1,162 @@
+use crate::{
+    batch::MotorMatrix, camera::Camera, collineation::Collineation, direction::Direction,
+    motor::Motor, plane::Plane, point::Point, Float,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Containment {
+    Outside,
+    Intersecting,
+    Inside,
+}
+
+//Six normalized planes facing inwards, ordered left, right, bottom, top, near, far. Volume tests
+//are conservative, so a shape reported as outside is guaranteed to be outside, while shapes near
+//the edges and corners may be reported as intersecting when they miss
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frustum {
+    pub planes: [Plane; 6],
+}
+impl Frustum {
+    pub fn new(planes: [Plane; 6]) -> Self {
+        Self {
+            planes: planes.map(|p| p.normalized()),
+        }
+    }
+    //Extracts the planes of any world to clip space map with depth running 0 to 1, in either
+    //direction
+    pub fn from_view_projection(m: &Collineation) -> Self {
+        let row = |r: usize| m.cols.map(|c| c[r]);
+        let [x, y, z, w] = [row(0), row(1), row(2), row(3)];
+        let plane = |a: [Float; 4], b: [Float; 4], s: Float| {
+            Plane::new(
+                a[0] + s * b[0],
+                a[1] + s * b[1],
+                a[2] + s * b[2],
+                a[3] + s * b[3],
+            )
+        };
+        Self::new([
+            plane(w, x, 1.0),
+            plane(w, x, -1.0),
+            plane(w, y, 1.0),
+            plane(w, y, -1.0),
+            //Depth starts at 0 rather than -1 as in OpenGL, so the near plane is just the z row
+            Plane::new(z[0], z[1], z[2], z[3]),
+            plane(w, z, -1.0),
+        ])
+    }
+    pub fn from_camera(camera: &Camera) -> Self {
+        let mut f = Self::from_view_projection(&camera.view_projection_matrix());
+        if camera.reverse_z {
+            f.planes.swap(4, 5);
+        }
+        f
+    }
+    //Pose places the camera, which looks down its -Z axis
+    pub fn perspective(pose: Motor, fov_y: Float, aspect: Float, near: Float, far: Float) -> Self {
+        let m = Collineation::from(pose.inverse())
+            .combine(Collineation::perspective(fov_y, aspect, near, far));
+        Self::from_view_projection(&m)
+    }
+
+    pub fn transformed(&self, m: Motor) -> Self {
+        let m = MotorMatrix::new(&m);
+        Self {
+            planes: self.planes.map(|p| m.transform_plane(p)),
+        }
+    }
+
+    //The near corners followed by the far ones, each going bottom left, bottom right, top left,
+    //top right. None when a corner is at infinity, as with an infinite far plane or planes that
+    //are parallel
+    pub fn corners(&self) -> Option<[Point; 8]> {
+        let [left, right, bottom, top, near, far] = self.planes;
+        let mut corners = [Point::ZERO; 8];
+        for (i, depth) in [near, far].into_iter().enumerate() {
+            for (j, (side, height)) in [(left, bottom), (right, bottom), (left, top), (right, top)]
+                .into_iter()
+                .enumerate()
+            {
+                let p = side.meet(height).meet(depth);
+                if p.w.abs() <= Float::EPSILON * p.x.abs().max(p.y.abs()).max(p.z.abs()) {
+                    return None;
+                }
+                corners[i * 4 + j] = p.scaled();
+            }
+        }
+        Some(corners)
+    }
+
+    pub fn contains_point(&self, p: Point) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(p) >= 0.0)
+    }
+    pub fn test_sphere(&self, center: Point, radius: Float) -> Containment {
+        self.classify(|plane| (plane.signed_distance(center), radius))
+    }
+    pub fn test_aabb(&self, min: Point, max: Point) -> Containment {
+        let (min, max) = (min.scaled(), max.scaled());
+        let center = Point::from_position(
+            (min.x + max.x) * 0.5,
+            (min.y + max.y) * 0.5,
+            (min.z + max.z) * 0.5,
+        );
+        let extent = Direction::new(
+            (max.x - min.x) * 0.5,
+            (max.y - min.y) * 0.5,
+            (max.z - min.z) * 0.5,
+        );
+        self.classify(|plane| {
+            let radius =
+                plane.x.abs() * extent.x + plane.y.abs() * extent.y + plane.z.abs() * extent.z;
+            (plane.signed_distance(center), radius)
+        })
+    }
+    //A box centered on the pose's origin and aligned with its axes
+    pub fn test_obb(&self, pose: Motor, half_extents: Direction) -> Containment {
+        let m = MotorMatrix::new(&pose);
+        let center = Point::from_position(m.t[0], m.t[1], m.t[2]);
+        let axes = [0, 1, 2].map(|i| Direction::new(m.r[0][i], m.r[1][i], m.r[2][i]));
+        let extents = [half_extents.x, half_extents.y, half_extents.z];
+        self.classify(|plane| {
+            let normal = plane.normal();
+            let radius: Float = axes
+                .iter()
+                .zip(extents)
+                .map(|(a, e)| normal.dot(*a).abs() * e)
+                .sum();
+            (plane.signed_distance(center), radius)
+        })
+    }
+    //Separating plane test in both directions. Can report an overlap for frustums that only come
+    //close along an edge, or when the corners of the other frustum are at infinity
+    pub fn intersects_frustum(&self, other: &Frustum) -> bool {
+        let separated = |a: &Frustum, corners: Option<[Point; 8]>| {
+            corners.is_some_and(|corners| {
+                a.planes
+                    .iter()
+                    .any(|plane| corners.iter().all(|c| plane.signed_distance(*c) < 0.0))
+            })
+        };
+        !separated(self, other.corners()) && !separated(other, self.corners())
+    }
+
+    //Takes the signed distance of the shape's center to each plane and its extent along the
+    //plane's normal
+    fn classify(&self, f: impl Fn(&Plane) -> (Float, Float)) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let (dist, radius) = f(plane);
+            if dist < -radius {
+                return Containment::Outside;
+            }
+            if dist < radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt;
+
+use crate::{motor::Motor, point::Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+impl NodeId {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HierarchyError {
+    //The new parent is the node itself or one of its descendants
+    Cycle,
+}
+impl fmt::Display for HierarchyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HierarchyError::Cycle => write!(f, "node would become its own ancestor"),
+        }
+    }
+}
+impl std::error::Error for HierarchyError {}
+
+//A tree of local motors. A node's world motor applies its local motor first and then its
+//parent's world motor, so world = local.combine(parent_world). World motors are cached and only
+//recomputed for changed subtrees when update is called. Invalid node ids panic, like out of
+//bounds indices
+#[derive(Debug, Clone, Default)]
+pub struct Hierarchy {
+    parents: Vec<Option<NodeId>>,
+    locals: Vec<Motor>,
+    worlds: Vec<Motor>,
+    dirty: Vec<bool>,
+    //Parents always come before their children
+    order: Vec<NodeId>,
+    order_dirty: bool,
+    any_dirty: bool,
+}
+impl Hierarchy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            parents: Vec::with_capacity(capacity),
+            locals: Vec::with_capacity(capacity),
+            worlds: Vec::with_capacity(capacity),
+            dirty: Vec::with_capacity(capacity),
+            order: Vec::with_capacity(capacity),
+            order_dirty: false,
+            any_dirty: false,
+        }
+    }
+
+    pub fn add(&mut self, local: Motor, parent: Option<NodeId>) -> NodeId {
+        if let Some(p) = parent {
+            assert!(p.0 < self.parents.len(), "Unknown parent node");
+        }
+        let id = NodeId(self.parents.len());
+        self.parents.push(parent);
+        self.locals.push(local);
+        self.worlds.push(local);
+        self.dirty.push(true);
+        //New nodes can only hang off existing ones, so appending keeps the order valid
+        self.order.push(id);
+        self.any_dirty = true;
+        id
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.parents[id.0]
+    }
+    //Nodes with parents before their children
+    pub fn order(&mut self) -> &[NodeId] {
+        self.sort();
+        &self.order
+    }
+
+    pub fn local(&self, id: NodeId) -> Motor {
+        self.locals[id.0]
+    }
+    pub fn set_local(&mut self, id: NodeId, local: Motor) {
+        self.locals[id.0] = local;
+        self.dirty[id.0] = true;
+        self.any_dirty = true;
+    }
+    //The world motor as of the last update
+    pub fn world(&self, id: NodeId) -> Motor {
+        debug_assert!(!self.any_dirty, "Hierarchy changed since the last update");
+        self.worlds[id.0]
+    }
+    //Moves a node to a world pose by changing its local motor
+    pub fn set_world(&mut self, id: NodeId, world: Motor) {
+        self.update();
+        let local = match self.parents[id.0] {
+            Some(p) => self.world_to_local(p, world),
+            None => world,
+        };
+        self.set_local(id, local);
+    }
+
+    //Recomputes the world motors of every changed node and its descendants
+    pub fn update(&mut self) {
+        if !self.any_dirty {
+            return;
+        }
+        self.sort();
+        for &id in &self.order {
+            let i = id.0;
+            match self.parents[i] {
+                Some(p) => {
+                    if self.dirty[p.0] {
+                        self.dirty[i] = true;
+                    }
+                    if self.dirty[i] {
+                        self.worlds[i] = self.locals[i].combine(self.worlds[p.0]);
+                    }
+                }
+                None => {
+                    if self.dirty[i] {
+                        self.worlds[i] = self.locals[i];
+                    }
+                }
+            }
+        }
+        self.dirty.fill(false);
+        self.any_dirty = false;
+    }
+
+    //Converts a motor relative to a node into world space, and back
+    pub fn local_to_world(&self, id: NodeId, local: Motor) -> Motor {
+        local.combine(self.world(id))
+    }
+    pub fn world_to_local(&self, id: NodeId, world: Motor) -> Motor {
+        world.combine(self.world(id).inverse())
+    }
+    pub fn point_to_world(&self, id: NodeId, p: Point) -> Point {
+        self.world(id).transform(p)
+    }
+    pub fn point_to_local(&self, id: NodeId, p: Point) -> Point {
+        self.world(id).inverse().transform(p)
+    }
+
+    //Moves a node and its subtree under a new parent without changing its world pose
+    pub fn reparent(&mut self, id: NodeId, parent: Option<NodeId>) -> Result<(), HierarchyError> {
+        let mut ancestor = parent;
+        while let Some(a) = ancestor {
+            if a == id {
+                return Err(HierarchyError::Cycle);
+            }
+            ancestor = self.parents[a.0];
+        }
+
+        self.update();
+        let world = self.worlds[id.0];
+        self.parents[id.0] = parent;
+        self.order_dirty = true;
+        self.set_world(id, world);
+        self.update();
+        Ok(())
+    }
+
+    //Breadth first from the roots. Only the order is rebuilt, node data stays indexed by id
+    fn sort(&mut self) {
+        if !self.order_dirty {
+            return;
+        }
+        let n = self.parents.len();
+        //Children grouped per parent, indexed by the running counts in starts
+        let mut starts = vec![0; n + 1];
+        for p in self.parents.iter().flatten() {
+            starts[p.0 + 1] += 1;
+        }
+        for i in 0..n {
+            starts[i + 1] += starts[i];
+        }
+        let mut fill = starts.clone();
+        let mut children = vec![NodeId(0); n];
+        for (i, p) in self.parents.iter().enumerate() {
+            if let Some(p) = p {
+                children[fill[p.0]] = NodeId(i);
+                fill[p.0] += 1;
+            }
+        }
+
+        self.order.clear();
+        self.order
+            .extend((0..n).filter(|i| self.parents[*i].is_none()).map(NodeId));
+        let mut next = 0;
+        while next < self.order.len() {
+            let i = self.order[next].0;
+            self.order
+                .extend_from_slice(&children[starts[i]..starts[i + 1]]);
+            next += 1;
+        }
+        self.order_dirty = false;
+    }
+}
@@ -0,0 +1,351 @@
+use crate::{
+    direction::Direction, limits::JointLimit, line::Line, motor::Motor, point::Point, Float,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JointKind {
+    //Turns freely around the joint's origin, up to an angle away from the rest pose
+    Ball {
+        max_angle: Float,
+    },
+    //Turns around a line in the joint's own space, which doesn't need to pass through its origin.
+    //Angles are measured from the rest pose, counterclockwise looking against the line's direction
+    Hinge {
+        axis: Line,
+        min_angle: Float,
+        max_angle: Float,
+    },
+    //Turns around the joint's origin within swing and twist limits
+    Limited(JointLimit),
+}
+
+//A joint's local motor is its rotation applied in its own space, followed by the rest motor that
+//places it in its parent, so local = rotation.combine(rest). World motors chain like Hierarchy,
+//with world = local.combine(parent_world)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Joint {
+    pub rest: Motor,
+    pub kind: JointKind,
+}
+impl Joint {
+    pub fn ball(rest: Motor) -> Self {
+        Self {
+            rest,
+            kind: JointKind::Ball {
+                max_angle: Float::INFINITY,
+            },
+        }
+    }
+    pub fn hinge(rest: Motor, axis: Line, min_angle: Float, max_angle: Float) -> Self {
+        Self {
+            rest,
+            kind: JointKind::Hinge {
+                axis: axis.normalized(),
+                min_angle,
+                max_angle,
+            },
+        }
+    }
+
+    //Projects a local motor onto the motions the joint allows
+    pub fn constrain(&self, local: Motor) -> Motor {
+        let twist = local.combine(self.rest.inverse()).log();
+        //Ball and limited joints only rotate about their origin, so only the direction is kept
+        let about_origin = Line::new(twist.vx, twist.vy, twist.vz, 0.0, 0.0, 0.0);
+        let rotation = match self.kind {
+            JointKind::Ball { max_angle } => {
+                let angle = twist.weight_norm();
+                let scale = if angle > max_angle {
+                    max_angle / angle
+                } else {
+                    1.0
+                };
+                Motor::exp(about_origin * scale)
+            }
+            JointKind::Hinge {
+                axis,
+                min_angle,
+                max_angle,
+            } => Motor::exp(
+                axis * twist
+                    .direction()
+                    .dot(axis.direction())
+                    .clamp(min_angle, max_angle),
+            ),
+            JointKind::Limited(limit) => limit.clamp(Motor::exp(about_origin)),
+        };
+        rotation.combine(self.rest)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chain {
+    //The world motor of the first joint's parent
+    pub root: Motor,
+    pub joints: Vec<Joint>,
+    //The point that should reach the target, in the last joint's space
+    pub effector: Point,
+}
+impl Chain {
+    pub fn new(root: Motor, joints: Vec<Joint>, effector: Point) -> Self {
+        Self {
+            root,
+            joints,
+            effector,
+        }
+    }
+    pub fn rest_locals(&self) -> Vec<Motor> {
+        self.joints.iter().map(|j| j.rest).collect()
+    }
+
+    pub fn worlds(&self, locals: &[Motor]) -> Vec<Motor> {
+        assert_eq!(locals.len(), self.joints.len(), "One local motor per joint");
+        let mut parent = self.root;
+        locals
+            .iter()
+            .map(|l| {
+                parent = l.combine(parent);
+                parent
+            })
+            .collect()
+    }
+    pub fn effector_position(&self, locals: &[Motor]) -> Point {
+        let world = self.worlds(locals).last().copied().unwrap_or(self.root);
+        world.transform(self.effector).scaled()
+    }
+
+    //Turns joint i so that a world point carried by it moves as close as the joint allows to
+    //another
+    fn turn_joint(&self, locals: &mut [Motor], worlds: &[Motor], i: usize, from: Point, to: Point) {
+        let parent = if i == 0 { self.root } else { worlds[i - 1] };
+        let world = worlds[i];
+        let turn = match self.joints[i].kind {
+            JointKind::Ball { .. } | JointKind::Limited(_) => {
+                let pivot = world.transform(Point::ZERO).scaled();
+                rotation_between(pivot, from, to)
+            }
+            JointKind::Hinge { axis, .. } => {
+                let axis = world.transform_line(axis).normalized();
+                let a = axis.direction();
+                let on_axis = Direction::new(
+                    a.y * axis.mz - a.z * axis.my,
+                    a.z * axis.mx - a.x * axis.mz,
+                    a.x * axis.my - a.y * axis.mx,
+                );
+                let flatten = |p: Point| {
+                    let d = p.scaled().to_direction_unchecked() - on_axis;
+                    d - a * d.dot(a)
+                };
+                let (u, v) = (flatten(from), flatten(to));
+                Motor::exp(axis * a.dot(u.cross(v)).atan2(u.dot(v)))
+            }
+        };
+        let local = world.combine(turn).combine(parent.inverse());
+        locals[i] = self.joints[i].constrain(local);
+    }
+}
+
+//The rotation about a pivot taking the direction towards one point onto the direction towards
+//another
+fn rotation_between(pivot: Point, from: Point, to: Point) -> Motor {
+    let p = pivot.to_direction_unchecked();
+    let u = from.scaled().to_direction_unchecked() - p;
+    let v = to.scaled().to_direction_unchecked() - p;
+    if u.magnitude_squared() == 0.0 || v.magnitude_squared() == 0.0 {
+        return Motor::IDENTITY;
+    }
+    let (u, v) = (u.normalized(), v.normalized());
+    let mut axis = u.cross(v);
+    let mut w = 1.0 + u.dot(v);
+    if w < 1e-6 {
+        //Opposite directions, so any perpendicular axis gives a half turn
+        axis = if u.x.abs() < 0.9 {
+            u.cross(Direction::RIGHT)
+        } else {
+            u.cross(Direction::UP)
+        };
+        w = 0.0;
+    }
+    let rotor = Motor::new(axis.x, axis.y, axis.z, w, 0.0, 0.0, 0.0, 0.0).normalized();
+    Motor::from_translation(-p.x, -p.y, -p.z)
+        .combine(rotor)
+        .combine(Motor::from_translation(p.x, p.y, p.z))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub locals: Vec<Motor>,
+    pub iterations: usize,
+    //Distance from the effector to the target
+    pub error: Float,
+    pub converged: bool,
+}
+
+//Cyclic coordinate descent, turning each joint from the effector back to the root in turn
+pub fn ccd(
+    chain: &Chain,
+    locals: &[Motor],
+    target: Point,
+    tolerance: Float,
+    max_iterations: usize,
+) -> Solution {
+    let target = target.scaled();
+    solve(
+        chain,
+        locals.to_vec(),
+        target,
+        tolerance,
+        max_iterations,
+        |locals| {
+            for i in (0..chain.joints.len()).rev() {
+                let worlds = chain.worlds(locals);
+                let effector = worlds[worlds.len() - 1].transform(chain.effector);
+                chain.turn_joint(locals, &worlds, i, effector, target);
+            }
+        },
+    )
+}
+
+//Forward and backward reaching on the joint positions, after which each joint is turned from the
+//root outwards to follow the new positions as closely as its constraints allow
+pub fn fabrik(
+    chain: &Chain,
+    locals: &[Motor],
+    target: Point,
+    tolerance: Float,
+    max_iterations: usize,
+) -> Solution {
+    let target = target.scaled();
+    solve(
+        chain,
+        locals.to_vec(),
+        target,
+        tolerance,
+        max_iterations,
+        |locals| {
+            let mut positions = chain_positions(chain, &chain.worlds(locals));
+            let lengths: Vec<Float> = positions.windows(2).map(|p| p[0].dist(p[1])).collect();
+            let root = positions[0];
+            let n = positions.len() - 1;
+
+            positions[n] = target;
+            for i in (0..n).rev() {
+                positions[i] = toward(positions[i + 1], positions[i], lengths[i]);
+            }
+            positions[0] = root;
+            for i in 0..n {
+                positions[i + 1] = toward(positions[i], positions[i + 1], lengths[i]);
+            }
+
+            for i in 0..chain.joints.len() {
+                let worlds = chain.worlds(locals);
+                let current = chain_positions(chain, &worlds);
+                chain.turn_joint(locals, &worlds, i, current[i + 1], positions[i + 1]);
+            }
+        },
+    )
+}
+
+//Joint origins followed by the effector
+fn chain_positions(chain: &Chain, worlds: &[Motor]) -> Vec<Point> {
+    worlds
+        .iter()
+        .map(|w| w.transform(Point::ZERO).scaled())
+        .chain([worlds[worlds.len() - 1].transform(chain.effector).scaled()])
+        .collect()
+}
+//The point at a distance from an anchor in the direction of another point
+fn toward(anchor: Point, p: Point, distance: Float) -> Point {
+    let d = p.to_direction_unchecked() - anchor.to_direction_unchecked();
+    let len = d.magnitude();
+    if len == 0.0 {
+        return p;
+    }
+    anchor + d * (distance / len)
+}
+
+fn solve(
+    chain: &Chain,
+    mut locals: Vec<Motor>,
+    target: Point,
+    tolerance: Float,
+    max_iterations: usize,
+    mut step: impl FnMut(&mut [Motor]),
+) -> Solution {
+    assert!(
+        !chain.joints.is_empty(),
+        "IK chains need at least one joint"
+    );
+    let mut iterations = 0;
+    let mut error = chain.effector_position(&locals).dist(target);
+    while error > tolerance && iterations < max_iterations {
+        step(&mut locals);
+        iterations += 1;
+        error = chain.effector_position(&locals).dist(target);
+    }
+    Solution {
+        locals,
+        iterations,
+        error,
+        converged: error <= tolerance,
+    }
+}
+
+//Analytic IK for a two joint chain, such as an arm or a leg. The first joint turns so that the
+//chain bends in the plane through the root joint, the target and the pole, towards the pole.
+//Joint constraints are ignored and targets out of reach straighten the chain towards them
+pub fn two_bone(chain: &Chain, locals: &[Motor], target: Point, pole: Point) -> Solution {
+    assert_eq!(
+        chain.joints.len(),
+        2,
+        "Two bone IK needs exactly two joints"
+    );
+    let target = target.scaled();
+    let mut locals = locals.to_vec();
+    let positions = chain_positions(chain, &chain.worlds(&locals));
+    let [a, b, c] = [positions[0], positions[1], positions[2]];
+    let (upper, lower) = (a.dist(b), b.dist(c));
+
+    let to_target = target.to_direction_unchecked() - a.to_direction_unchecked();
+    let distance = to_target.magnitude();
+    let reach = distance.clamp((upper - lower).abs(), upper + lower);
+    let u = if to_target.magnitude_squared() > 0.0 {
+        to_target.normalized()
+    } else {
+        (c.to_direction_unchecked() - a.to_direction_unchecked()).normalized()
+    };
+    let to_pole = pole.scaled().to_direction_unchecked() - a.to_direction_unchecked();
+    let side = to_pole - u * to_pole.dot(u);
+    let n = if side.magnitude_squared() > 0.0 {
+        side.normalized()
+    } else {
+        let bend = b.to_direction_unchecked() - a.to_direction_unchecked();
+        (bend - u * bend.dot(u)).normalized()
+    };
+
+    let cos =
+        ((upper * upper + reach * reach - lower * lower) / (2.0 * upper * reach)).clamp(-1.0, 1.0);
+    let sin = (1.0 - cos * cos).sqrt();
+    let elbow = a + (u * cos + n * sin) * upper;
+    let hand = a + u * reach;
+
+    let turn = rotation_between(a, b, elbow);
+    let world = chain.worlds(&locals)[0].combine(turn);
+    locals[0] = world.combine(chain.root.inverse());
+    let worlds = chain.worlds(&locals);
+    let current = worlds[1].transform(chain.effector);
+    let turn = rotation_between(worlds[1].transform(Point::ZERO).scaled(), current, hand);
+    locals[1] = worlds[1].combine(turn).combine(worlds[0].inverse());
+
+    let error = chain.effector_position(&locals).dist(target);
+    Solution {
+        locals,
+        iterations: 1,
+        error,
+        //Whether the target was within reach
+        converged: reach == distance,
+    }
+}
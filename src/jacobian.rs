@@ -0,0 +1,96 @@
+use crate::{batch::MotorMatrix, motor::Motor, point::Point, Float};
+
+//Jacobians are taken with respect to a twist (see Motor::exp) applied after the motor, so a
+//perturbed motor is m.combine(Motor::exp(twist)). Twist columns are ordered as the angular
+//velocity x, y, z followed by the linear velocity x, y, z, matching a Line's v then m
+
+const IDENTITY_6: [[Float; 6]; 6] = [
+    [1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+];
+
+impl Motor {
+    //Derivative of the weighted x, y, z of self.transform(p) with respect to the twist
+    pub fn transform_jacobian(&self, p: Point) -> [[Float; 6]; 3] {
+        let q = self.transform(p);
+        let w = q.w;
+        [
+            [0.0, q.z, -q.y, w, 0.0, 0.0],
+            [-q.z, 0.0, q.x, 0.0, w, 0.0],
+            [q.y, -q.x, 0.0, 0.0, 0.0, w],
+        ]
+    }
+    //Derivative of self.transform(p) with respect to the weighted x, y, z of p
+    pub fn transform_jacobian_point(&self) -> [[Float; 3]; 3] {
+        MotorMatrix::new(self).r
+    }
+
+    //Maps a twist applied before the motor to the equivalent one applied after it. This is the
+    //same matrix that transforms a line
+    pub fn adjoint(&self) -> [[Float; 6]; 6] {
+        let m = MotorMatrix::new(self);
+        let (r, t) = (m.r, m.t);
+
+        let mut a = [[0.0; 6]; 6];
+        for i in 0..3 {
+            for j in 0..3 {
+                a[i][j] = r[i][j];
+                a[i + 3][j + 3] = r[i][j];
+            }
+        }
+        //[t]x * R
+        for j in 0..3 {
+            a[3][j] = t[1] * r[2][j] - t[2] * r[1][j];
+            a[4][j] = t[2] * r[0][j] - t[0] * r[2][j];
+            a[5][j] = t[0] * r[1][j] - t[1] * r[0][j];
+        }
+        a
+    }
+
+    //Derivatives of self.combine(other) with respect to the twists of self and of other
+    pub fn combine_jacobians(&self, other: Motor) -> ([[Float; 6]; 6], [[Float; 6]; 6]) {
+        (other.adjoint(), IDENTITY_6)
+    }
+
+    //Derivative of from_euler_pos_and_rot's twist with respect to its six parameters, in the
+    //same order as the arguments
+    pub fn from_euler_pos_and_rot_jacobian(
+        pos_x: Float,
+        pos_y: Float,
+        pos_z: Float,
+        rot_x: Float,
+        rot_y: Float,
+        _rot_z: Float,
+    ) -> [[Float; 6]; 6] {
+        let (sin_x, cos_x) = rot_x.sin_cos();
+        let (sin_y, cos_y) = rot_y.sin_cos();
+
+        //Rotation axes in world space. Rotations apply z, x, then y, so each axis is carried by
+        //the rotations that follow it
+        let axes = [
+            [cos_y, 0.0, -sin_y],
+            [0.0, 1.0, 0.0],
+            [cos_x * sin_y, -sin_x, cos_x * cos_y],
+        ];
+        let t = [pos_x, pos_y, pos_z];
+
+        let mut j = [[0.0; 6]; 6];
+        for i in 0..3 {
+            j[i + 3][i] = 1.0;
+
+            //The rotation pivots around the position, so it also moves the origin
+            let w = axes[i];
+            j[0][i + 3] = w[0];
+            j[1][i + 3] = w[1];
+            j[2][i + 3] = w[2];
+            j[3][i + 3] = t[1] * w[2] - t[2] * w[1];
+            j[4][i + 3] = t[2] * w[0] - t[0] * w[2];
+            j[5][i + 3] = t[0] * w[1] - t[1] * w[0];
+        }
+        j
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{
+    motor::{motor_product, Motor},
+    point::Point,
+    rotor::Rotor,
+    Float,
+};
+
+//World-space types that are always double precision, independent of the f64 feature. Only the
+//result of rebasing them against an origin is converted down to Float for rendering
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct WorldPoint {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+impl WorldPoint {
+    pub const ZERO: WorldPoint = WorldPoint::new(0.0, 0.0, 0.0);
+
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn dist(&self, other: WorldPoint) -> f64 {
+        let x = other.x - self.x;
+        let y = other.y - self.y;
+        let z = other.z - self.z;
+        (x * x + y * y + z * z).sqrt()
+    }
+
+    pub fn relative_to(&self, origin: &WorldMotor) -> Point {
+        RenderOrigin::new(*origin).point(*self)
+    }
+}
+impl From<Point> for WorldPoint {
+    #[allow(clippy::unnecessary_cast)]
+    fn from(value: Point) -> Self {
+        let p = value.scaled();
+        Self::new(p.x as f64, p.y as f64, p.z as f64)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct WorldMotor {
+    pub vx: f64,
+    pub vy: f64,
+    pub vz: f64,
+    pub vw: f64,
+    pub mx: f64,
+    pub my: f64,
+    pub mz: f64,
+    pub mw: f64,
+}
+impl Default for WorldMotor {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+impl WorldMotor {
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0);
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        vx: f64,
+        vy: f64,
+        vz: f64,
+        vw: f64,
+        mx: f64,
+        my: f64,
+        mz: f64,
+        mw: f64,
+    ) -> Self {
+        Self {
+            vx,
+            vy,
+            vz,
+            vw,
+            mx,
+            my,
+            mz,
+            mw,
+        }
+    }
+    #[inline]
+    pub fn from_translation(x: f64, y: f64, z: f64) -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0, x * 0.5, y * 0.5, z * 0.5, 0.0)
+    }
+    //Rotate, then translate. The translation keeps full precision
+    #[inline]
+    pub fn from_rotation_and_translation(rotation: Rotor, x: f64, y: f64, z: f64) -> Self {
+        Self::from(Motor::from(rotation)).combine(Self::from_translation(x, y, z))
+    }
+
+    #[inline]
+    pub fn combine(&self, other: WorldMotor) -> Self {
+        motor_product!(Self::new, self, other)
+    }
+    #[inline]
+    pub fn transform(&self, p: WorldPoint) -> WorldPoint {
+        let s =
+            2.0 / (self.vx * self.vx + self.vy * self.vy + self.vz * self.vz + self.vw * self.vw);
+
+        let ax = self.vy * p.z - self.vz * p.y + self.mx;
+        let ay = self.vz * p.x - self.vx * p.z + self.my;
+        let az = self.vx * p.y - self.vy * p.x + self.mz;
+
+        WorldPoint::new(
+            p.x + s * (self.vw * ax + (self.vy * az - self.vz * ay) - self.mw * self.vx),
+            p.y + s * (self.vw * ay + (self.vz * ax - self.vx * az) - self.mw * self.vy),
+            p.z + s * (self.vw * az + (self.vx * ay - self.vy * ax) - self.mw * self.vz),
+        )
+    }
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let s =
+            1.0 / (self.vx * self.vx + self.vy * self.vy + self.vz * self.vz + self.vw * self.vw);
+        Self::new(
+            -self.vx * s,
+            -self.vy * s,
+            -self.vz * s,
+            self.vw * s,
+            -self.mx * s,
+            -self.my * s,
+            -self.mz * s,
+            self.mw * s,
+        )
+    }
+    #[inline]
+    pub fn translation_euler(&self) -> WorldPoint {
+        self.transform(WorldPoint::ZERO)
+    }
+
+    //Moves the whole pose by an offset, applied after the motor
+    #[inline]
+    pub fn shifted(&self, x: f64, y: f64, z: f64) -> Self {
+        self.combine(Self::from_translation(x, y, z))
+    }
+
+    pub fn relative_to(&self, origin: &WorldMotor) -> Motor {
+        RenderOrigin::new(*origin).motor(*self)
+    }
+}
+impl From<Motor> for WorldMotor {
+    #[allow(clippy::unnecessary_cast)]
+    fn from(value: Motor) -> Self {
+        Self::new(
+            value.vx as f64,
+            value.vy as f64,
+            value.vz as f64,
+            value.vw as f64,
+            value.mx as f64,
+            value.my as f64,
+            value.mz as f64,
+            value.mw as f64,
+        )
+    }
+}
+
+//Rebases world data against an origin, typically the camera pose or just its position. All of the
+//arithmetic happens in f64, so only the small camera-relative result is rounded
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderOrigin {
+    inverse: WorldMotor,
+}
+impl RenderOrigin {
+    pub fn new(origin: WorldMotor) -> Self {
+        Self {
+            inverse: origin.inverse(),
+        }
+    }
+    //Keeps the world axes and only recenters on the position
+    pub fn from_position(p: WorldPoint) -> Self {
+        Self::new(WorldMotor::from_translation(p.x, p.y, p.z))
+    }
+
+    #[allow(clippy::unnecessary_cast)]
+    pub fn motor(&self, world: WorldMotor) -> Motor {
+        let m = world.combine(self.inverse);
+        Motor::new(
+            m.vx as Float,
+            m.vy as Float,
+            m.vz as Float,
+            m.vw as Float,
+            m.mx as Float,
+            m.my as Float,
+            m.mz as Float,
+            m.mw as Float,
+        )
+    }
+    #[allow(clippy::unnecessary_cast)]
+    pub fn point(&self, world: WorldPoint) -> Point {
+        let p = self.inverse.transform(world);
+        Point::from_position(p.x as Float, p.y as Float, p.z as Float)
+    }
+
+    pub fn motors(&self, world: &[WorldMotor], out: &mut [Motor]) {
+        assert_eq!(
+            world.len(),
+            out.len(),
+            "Source and destination lengths differ"
+        );
+        for (o, w) in out.iter_mut().zip(world) {
+            *o = self.motor(*w);
+        }
+    }
+    pub fn points(&self, world: &[WorldPoint], out: &mut [Point]) {
+        assert_eq!(
+            world.len(),
+            out.len(),
+            "Source and destination lengths differ"
+        );
+        for (o, w) in out.iter_mut().zip(world) {
+            *o = self.point(*w);
+        }
+    }
+}
+
+//Moves every pose and point of a scene so that new_origin becomes the origin
+pub fn shift_origin(motors: &mut [WorldMotor], points: &mut [WorldPoint], new_origin: WorldPoint) {
+    for m in motors {
+        *m = m.shifted(-new_origin.x, -new_origin.y, -new_origin.z);
+    }
+    for p in points {
+        p.x -= new_origin.x;
+        p.y -= new_origin.y;
+        p.z -= new_origin.z;
+    }
+}
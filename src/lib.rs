@@ -1,8 +1,37 @@
+//Each type keeps its general methods in its own file. Modules for a single feature, such as batch,
+//jacobian, predicates and vertex, add their methods to the core types in one impl block of their
+//own, so the feature stays in one place
+pub mod animation;
+pub mod batch;
+pub mod blend;
+pub mod camera;
+pub mod collineation;
+pub mod controller;
+pub mod convention;
+pub mod direction;
+pub mod fixed;
+pub mod frame;
+pub mod frustum;
+pub mod hierarchy;
+pub mod ik;
+pub mod jacobian;
+pub mod large_world;
+pub mod limits;
 pub mod line;
 pub mod motor;
 pub mod plane;
 pub mod point;
+pub mod predicates;
 pub mod prelude;
+pub mod projective;
+pub mod rotor;
+pub mod simd;
+pub mod skinning;
+pub mod spline;
+pub mod tf;
+pub mod translator;
+pub mod unit;
+pub mod vertex;
 
 #[cfg(not(feature = "f64"))]
 pub type Float = f32;
@@ -11,8 +40,15 @@ pub type Float = f64;
 
 #[cfg(test)]
 mod test {
+    use direction::Direction;
+    use line::Line;
     use motor::Motor;
+    use plane::Plane;
     use point::Point;
+    use rotor::Rotor;
+    use simd::{MotorX4, MotorX8, PointX4, PointX8};
+    use translator::Translator;
+    use unit::{UnitLine, UnitMotor, UnitPlane};
 
     use super::*;
 
@@ -136,6 +172,25 @@ mod test {
             .is_close(separate_motors.transform(point)));
     }
 
+    #[test]
+    fn combine_screw_motors() {
+        //Rotations with translations off their axes, so every motor has a nonzero mw
+        let motors = [
+            Motor::from_euler_pos_and_rot(3.0, 2.0, 1.0, 2.5, 3.0, 4.0),
+            Motor::from_euler_pos_and_rot(-1.0, 0.5, 2.0, 0.3, -1.2, 0.7),
+            Motor::from_rotation_around_axis(0.0, 0.0, 1.0, 1.1)
+                .combine(Motor::from_translation(0.5, -1.0, 3.0)),
+            Motor::from_translation(1.0, 2.0, 3.0),
+        ];
+        let point = Point::from_position(0.5, -1.0, 2.0);
+        for a in motors {
+            for b in motors {
+                let expected = b.transform(a.transform(point));
+                assert!(a.combine(b).transform(point).is_close(expected));
+            }
+        }
+    }
+
     #[test]
     fn motor_translation_euler() {
         let motor = Motor::from_euler_pos_and_rot(1.0, 2.0, 3.0, 2.0, 0.7, 5.0);
@@ -163,6 +218,1956 @@ mod test {
             .is_close(rotation.combine(translation).transform(point)));
     }
 
+    #[test]
+    fn rotor_translator_products() {
+        let rotor = Rotor::from_euler_angles(0.4, 1.3, -0.8);
+        let other_rotor = Rotor::from_rotation_around_axis(0.0, 0.0, 1.0, 2.1);
+        let translator = Translator::from_translation(1.0, -2.0, 3.5);
+        let other_translator = Translator::from_translation(-0.5, 0.25, 2.0);
+        let motor = Motor::from_euler_pos_and_rot(3.0, 2.0, 1.0, 2.5, 3.0, 4.0);
+
+        let rotor_m = Motor::from(rotor);
+        let translator_m = Motor::from(translator);
+        let point = Point::from_position(0.5, -1.5, 2.0);
+        let (r, t, m) = (
+            rotor.transform(point),
+            translator.transform(point),
+            motor.transform(point),
+        );
+
+        //Each product should match applying its two transforms one after the other
+        let pairs = [
+            (
+                rotor.combine_translator(translator),
+                translator.transform(r),
+            ),
+            (translator.combine_rotor(rotor), rotor.transform(t)),
+            (rotor.combine_motor(motor), motor.transform(r)),
+            (translator.combine_motor(motor), motor.transform(t)),
+            (motor.combine_rotor(rotor), rotor.transform(m)),
+            (
+                motor.combine_translator(translator),
+                translator.transform(m),
+            ),
+            (rotor.combine(other_rotor).into(), other_rotor.transform(r)),
+            (
+                translator.combine(other_translator).into(),
+                other_translator.transform(t),
+            ),
+        ];
+        for (specialized, expected) in pairs {
+            assert!(specialized.transform(point).is_close(expected));
+        }
+
+        assert!(rotor.transform(point).is_close(rotor_m.transform(point)));
+        assert!(translator
+            .transform(point)
+            .is_close(translator_m.transform(point)));
+        assert!(rotor
+            .inverse()
+            .transform(rotor.transform(point))
+            .is_close(point));
+        assert!(translator
+            .inverse()
+            .transform(translator.transform(point))
+            .is_close(point));
+    }
+
+    #[test]
+    fn rotor_translator_match_motor_constructors() {
+        let point = Point::from_position(1.0, 2.0, 3.0);
+        let motor = Motor::from_euler_pos_and_rot(7.0, 3.5, 4.0, 0.3, 0.6, 0.9);
+        let split = Rotor::from_euler_angles(0.3, 0.6, 0.9)
+            .combine_translator(Translator::from_translation(7.0, 3.5, 4.0));
+
+        assert!(motor.transform(point).is_close(split.transform(point)));
+        assert!(Translator::from_translation(7.0, 3.5, 4.0)
+            .translation_euler()
+            .is_close(motor.translation_euler()));
+    }
+
+    #[test]
+    fn direction_transform() {
+        let motor = Motor::from_euler_pos_and_rot(3.0, 2.0, 1.0, 2.5, 3.0, 4.0);
+        let dir = Direction::new(1.0, -2.0, 0.5);
+
+        let fast = motor.transform_direction(dir);
+        let full = motor.transform(dir.into());
+
+        assert!(full.is_close(fast.into()));
+        assert_eq!(full.w, 0.0);
+        assert!((fast.magnitude() - dir.magnitude()).abs() < 0.001);
+        assert!(Motor::from_translation(5.0, 6.0, 7.0)
+            .transform_direction(dir)
+            .is_close(dir));
+        assert!(Rotor::from_euler_angles(2.5, 3.0, 4.0)
+            .transform_direction(dir)
+            .is_close(fast));
+    }
+
+    #[test]
+    fn direction_point_conversion() {
+        let dir = Direction::new(3.0, 0.0, 4.0);
+        assert!((dir.normalized().magnitude() - 1.0).abs() < 0.001);
+        assert!(Point::from(dir).to_direction_unchecked().is_close(dir));
+        assert!((Point::from_position(1.0, 1.0, 1.0) + dir)
+            .is_close(Point::from_position(4.0, 1.0, 5.0)));
+        assert!(Direction::FORWARD
+            .cross(Direction::UP)
+            .is_close(Direction::RIGHT));
+    }
+
+    #[test]
+    fn non_unit_motor() {
+        let motor = Motor::from_euler_pos_and_rot(1.0, -2.0, 0.5, 0.3, 1.2, -0.4);
+        let scaled = Motor::new(
+            motor.vx * 3.0,
+            motor.vy * 3.0,
+            motor.vz * 3.0,
+            motor.vw * 3.0,
+            motor.mx * 3.0,
+            motor.my * 3.0,
+            motor.mz * 3.0,
+            motor.mw * 3.0,
+        );
+        let point = Point::from_position(2.0, 3.0, 4.0);
+
+        assert!((scaled.weight_norm() - 3.0).abs() < 0.001);
+        assert!((scaled.normalized().weight_norm() - 1.0).abs() < 0.001);
+        assert!(scaled.transform(point).is_close(motor.transform(point)));
+        assert!(scaled
+            .inverse()
+            .transform(scaled.transform(point))
+            .is_close(point));
+        assert!(scaled
+            .translation_euler()
+            .is_close(motor.translation_euler()));
+        assert!(scaled
+            .transform_direction(Direction::UP)
+            .is_close(motor.transform_direction(Direction::UP)));
+        assert!(UnitMotor::new(scaled)
+            .transform(point)
+            .is_close(motor.transform(point)));
+        assert!(scaled
+            .normalized()
+            .transform(point)
+            .is_close(motor.transform(point)));
+    }
+
+    #[test]
+    fn unit_motor() {
+        let motor = UnitMotor::from_rotation_around_axis(
+            Direction::new(0.0, 2.0, 0.0),
+            float_consts::PI * 0.5,
+        )
+        .unwrap()
+        .combine(UnitMotor::from_translation(1.0, 2.0, 3.0));
+        let raw = Motor::from_rotation_around_axis(0.0, 1.0, 0.0, float_consts::PI * 0.5)
+            .combine(Motor::from_translation(1.0, 2.0, 3.0));
+        let point = Point::from_position(1.0, -1.0, 0.0);
+
+        assert!(motor.transform(point).is_close(raw.transform(point)));
+        assert!(motor
+            .inverse()
+            .transform(motor.transform(point))
+            .is_close(point));
+        assert!(motor.translation_euler().is_close(raw.translation_euler()));
+
+        assert_eq!(
+            UnitMotor::from_rotation_around_axis(Direction::ZERO, 1.0),
+            None
+        );
+
+        //Constraint violation is projected out
+        let unit = UnitMotor::new(Motor::new(0.0, 0.0, 0.0, 2.0, 1.0, 0.0, 0.0, 0.5)).motor();
+        assert!((unit.vw * unit.mw).abs() < 0.001);
+    }
+
+    #[test]
+    fn unit_line_and_plane() {
+        let line = Point::from_position(1.0, 0.0, 0.0).join(Point::from_position(1.0, 4.0, 0.0));
+        let point = Point::new(6.0, 2.0, 0.0, 2.0);
+
+        assert!((line.distance_to_point(point) - 2.0).abs() < 0.001);
+        assert!((UnitLine::new(line).distance_to_point(point) - 2.0).abs() < 0.001);
+        assert!(UnitLine::new(line)
+            .direction()
+            .is_close(Direction::new(0.0, 1.0, 0.0)));
+
+        let plane = plane::Plane::new(0.0, 3.0, 0.0, -6.0);
+        assert!((plane.signed_distance(point) + 1.0).abs() < 0.001);
+        assert!((UnitPlane::new(plane).signed_distance(point) + 1.0).abs() < 0.001);
+    }
+
+    fn sample_motors<const N: usize>() -> [Motor; N] {
+        std::array::from_fn(|i| {
+            let i = i as Float;
+            Motor::from_euler_pos_and_rot(i, -2.0 * i, 0.5 + i, 0.3 * i, 1.2 - i, -0.4 * i)
+        })
+    }
+    fn sample_points<const N: usize>() -> [Point; N] {
+        std::array::from_fn(|i| {
+            let i = i as Float;
+            Point::new(1.5 * i, 2.0 - i, 0.25 * i * i, 1.0 + (i * 0.5).floor())
+        })
+    }
+
+    #[test]
+    fn simd_matches_scalar_x4() {
+        let motors = sample_motors::<4>();
+        let others = sample_motors::<5>();
+        let points = sample_points::<4>();
+        let others_p = sample_points::<5>();
+
+        let motors_x = MotorX4::from_motors(motors);
+        let others_x = MotorX4::from_motors([others[1], others[2], others[3], others[4]]);
+        let points_x = PointX4::from_points(points);
+        let others_px = PointX4::from_points([others_p[1], others_p[2], others_p[3], others_p[4]]);
+
+        let transformed = motors_x.transform(&points_x).to_points();
+        let combined = motors_x.combine(&others_x).to_motors();
+        let joined = points_x.join(&others_px).to_lines();
+        let dots = points_x.dot(&others_px);
+        for i in 0..4 {
+            assert_eq!(
+                transformed[i],
+                UnitMotor::new_unchecked(motors[i]).transform(points[i])
+            );
+            assert_eq!(combined[i], motors[i].combine(others[i + 1]));
+            //Checked against sequential transforms too, so SIMD and scalar can't drift together
+            let sequential = others[i + 1].transform(motors[i].transform(points[i]));
+            assert!(combined[i].transform(points[i]).is_close(sequential));
+            assert_eq!(joined[i], points[i].join(others_p[i + 1]));
+            assert_eq!(dots[i], points[i].dot(others_p[i + 1]));
+        }
+    }
+
+    #[test]
+    fn simd_matches_scalar_x8() {
+        let motors = sample_motors::<8>();
+        let points = sample_points::<8>();
+        let motor = Motor::from_euler_pos_and_rot(1.0, 2.0, 3.0, 0.1, 0.2, 0.3);
+
+        let transformed = MotorX8::from_motors(motors)
+            .transform(&PointX8::from_points(points))
+            .to_points();
+        let splatted = MotorX8::splat(motor)
+            .transform(&PointX8::from_points(points))
+            .to_points();
+        let combined = MotorX8::from_motors(motors)
+            .combine(&MotorX8::splat(motor))
+            .to_motors();
+        for i in 0..8 {
+            assert_eq!(
+                transformed[i],
+                UnitMotor::new_unchecked(motors[i]).transform(points[i])
+            );
+            assert_eq!(
+                splatted[i],
+                UnitMotor::new_unchecked(motor).transform(points[i])
+            );
+            assert_eq!(combined[i], motors[i].combine(motor));
+            assert!(combined[i]
+                .transform(points[i])
+                .is_close(motor.transform(transformed[i])));
+        }
+    }
+
+    fn lines_close(a: Line, b: Line) -> bool {
+        [
+            a.vx - b.vx,
+            a.vy - b.vy,
+            a.vz - b.vz,
+            a.mx - b.mx,
+            a.my - b.my,
+            a.mz - b.mz,
+        ]
+        .iter()
+        .all(|d| d.abs() < 0.01)
+    }
+
+    #[test]
+    fn motor_transform_plane_line() {
+        let motor = Motor::from_euler_pos_and_rot(1.0, -2.0, 0.5, 0.3, 1.2, -0.4);
+        let a = Point::from_position(1.0, 2.0, 3.0);
+        let b = Point::from_position(-1.0, 0.5, 2.0);
+        let c = Point::from_position(0.0, -3.0, 1.0);
+        let (ta, tb, tc) = (motor.transform(a), motor.transform(b), motor.transform(c));
+
+        assert!(lines_close(motor.transform_line(a.join(b)), ta.join(tb)));
+
+        let plane = motor.transform_plane(a.join(b).join(c));
+        let expected = ta.join(tb).join(tc);
+        for p in [ta, tb, tc] {
+            assert!(plane.signed_distance(p).abs() < 0.001);
+        }
+        assert!(plane
+            .normal()
+            .normalized()
+            .is_close(expected.normal().normalized()));
+    }
+
+    #[test]
+    fn motor_transform_slices() {
+        let motor = Motor::from_euler_pos_and_rot(1.0, -2.0, 0.5, 0.3, 1.2, -0.4);
+        let points: Vec<Point> = (0..100)
+            .map(|i| Point::from_position(i as Float, 0.5 * i as Float, -(i as Float)))
+            .collect();
+
+        let mut transformed = vec![Point::ZERO; points.len()];
+        motor.transform_slice(&points, &mut transformed);
+        let mut in_place = points.clone();
+        motor.transform_slice_in_place(&mut in_place);
+        for ((p, t), i) in points.iter().zip(&transformed).zip(&in_place) {
+            assert!(motor.transform(*p).is_close(*t));
+            assert_eq!(t, i);
+        }
+
+        let planes: Vec<Plane> = points
+            .windows(2)
+            .map(|w| w[0].expand_plane(Plane::new(0.0, 1.0, 0.0, 0.0)).join(w[1]))
+            .collect();
+        let mut transformed = planes.clone();
+        motor.transform_plane_slice_in_place(&mut transformed);
+        for (p, t) in planes.iter().zip(&transformed) {
+            let expected = motor.transform_plane(*p);
+            assert!(expected.normal().is_close(t.normal()));
+            assert!((expected.w - t.w).abs() < 0.01);
+        }
+
+        let lines: Vec<Line> = points.windows(2).map(|w| w[0].join(w[1])).collect();
+        let mut transformed = vec![Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0); lines.len()];
+        motor.transform_line_slice(&lines, &mut transformed);
+        for (l, t) in lines.iter().zip(&transformed) {
+            assert!(lines_close(motor.transform_line(*l), *t));
+        }
+
+        let dirs = [Direction::UP, Direction::RIGHT, Direction::BACK];
+        let mut transformed = dirs;
+        motor.transform_direction_slice_in_place(&mut transformed);
+        for (d, t) in dirs.iter().zip(&transformed) {
+            assert!(motor.transform_direction(*d).is_close(*t));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn motor_transform_slice_parallel() {
+        let motor = Motor::from_euler_pos_and_rot(1.0, -2.0, 0.5, 0.3, 1.2, -0.4);
+        let points: Vec<Point> = (0..batch::PARALLEL_THRESHOLD * 2)
+            .map(|i| Point::from_position(i as Float * 0.001, 1.0, 2.0))
+            .collect();
+
+        let mut transformed = vec![Point::ZERO; points.len()];
+        motor.transform_slice(&points, &mut transformed);
+        for (p, t) in points.iter().zip(&transformed) {
+            assert!(motor.transform(*p).is_close(*t));
+        }
+    }
+
+    #[test]
+    fn f16_conversion() {
+        for v in [0.0, -0.0, 1.0, -2.5, 0.333, 65504.0, 6.1e-5, 3.0e-6] {
+            let h = vertex::f32_to_f16(v);
+            assert!((vertex::f16_to_f32(h) - v).abs() <= v.abs() * 0.001 + 1.0e-7);
+        }
+        assert_eq!(vertex::f32_to_f16(1.0), 0x3c00);
+        assert_eq!(vertex::f32_to_f16(-2.0), 0xc000);
+        assert_eq!(vertex::f32_to_f16(1.0e6), 0x7c00);
+        assert!(vertex::f16_to_f32(vertex::f32_to_f16(f32::NAN)).is_nan());
+    }
+
+    #[test]
+    fn motor_transform_vertex_buffer() {
+        use vertex::{NormalKind, ScalarFormat, VertexLayout};
+
+        //position f32 x3, normal f16 x3, padding, tangent f32 x4, uv f32 x2
+        let layout = VertexLayout::new(44)
+            .with_position(0, ScalarFormat::F32)
+            .with_normal(12, ScalarFormat::F16, NormalKind::Direction)
+            .with_tangent(20, ScalarFormat::F32);
+        let motor = Motor::from_euler_pos_and_rot(1.0, -2.0, 0.5, 0.3, 1.2, -0.4);
+
+        let positions = [
+            Point::from_position(1.0, 2.0, 3.0),
+            Point::from_position(-4.0, 0.5, 2.0),
+        ];
+        let normal = Direction::new(0.0, 1.0, 0.0);
+        let tangent = Direction::new(1.0, 0.0, 0.0);
+
+        let mut buf = vec![0u8; layout.stride * positions.len() + 3];
+        for (vertex, p) in buf.chunks_exact_mut(layout.stride).zip(positions) {
+            let mut floats = [0.0f32; 4];
+            p.copy_to_buffer_32(&mut floats);
+            for (i, f) in floats[..3].iter().enumerate() {
+                vertex[i * 4..i * 4 + 4].copy_from_slice(&f.to_ne_bytes());
+            }
+            for (i, f) in normal.into_buffer_32().iter().enumerate() {
+                vertex[12 + i * 2..14 + i * 2]
+                    .copy_from_slice(&vertex::f32_to_f16(*f).to_ne_bytes());
+            }
+            for (i, f) in tangent.into_buffer_32().iter().chain(&[-1.0]).enumerate() {
+                vertex[20 + i * 4..24 + i * 4].copy_from_slice(&f.to_ne_bytes());
+            }
+            vertex[36..40].copy_from_slice(&0.25f32.to_ne_bytes());
+        }
+
+        motor.transform_vertex_buffer(&mut buf, &layout);
+
+        let f32_at =
+            |v: &[u8], o: usize| Float::from(f32::from_ne_bytes(v[o..o + 4].try_into().unwrap()));
+        let f16_at = |v: &[u8], o: usize| {
+            Float::from(vertex::f16_to_f32(u16::from_ne_bytes(
+                v[o..o + 2].try_into().unwrap(),
+            )))
+        };
+        for (vertex, p) in buf.chunks_exact(layout.stride).zip(positions) {
+            let pos = Point::from_position(f32_at(vertex, 0), f32_at(vertex, 4), f32_at(vertex, 8));
+            let n = Direction::new(f16_at(vertex, 12), f16_at(vertex, 14), f16_at(vertex, 16));
+            let t = Direction::new(f32_at(vertex, 20), f32_at(vertex, 24), f32_at(vertex, 28));
+
+            assert!(pos.is_close(motor.transform(p)));
+            assert!(n.is_close(motor.transform_direction(normal)));
+            assert!(t.is_close(motor.transform_direction(tangent)));
+            assert_eq!(f32_at(vertex, 32), -1.0);
+            assert_eq!(f32_at(vertex, 36), 0.25);
+        }
+    }
+
+    #[test]
+    fn large_world_rebasing() {
+        use large_world::{shift_origin, RenderOrigin, WorldMotor, WorldPoint};
+
+        let far = 10_000_000.0;
+        let camera = WorldMotor::from_rotation_and_translation(
+            Rotor::from_rotation_around_axis(0.0, 1.0, 0.0, float_consts::PI * 0.5),
+            far,
+            0.0,
+            far + 0.5,
+        );
+        let object = WorldMotor::from_translation(far + 0.125, 2.0, far + 0.5);
+
+        //The object is 0.125 along world +X from the camera, which is behind the yawed camera
+        let relative = object.relative_to(&camera);
+        assert!((relative.translation_euler().x - 0.0).abs() < 1.0e-5);
+        assert!((relative.translation_euler().y - 2.0).abs() < 1.0e-5);
+        assert!((relative.translation_euler().z - 0.125).abs() < 1.0e-5);
+
+        let origin = RenderOrigin::from_position(WorldPoint::new(far, 0.0, far));
+        let p = origin.point(WorldPoint::new(far + 0.001, 1.0, far - 0.002));
+        assert!((p.x - 0.001).abs() < 1.0e-7);
+        assert!((p.z + 0.002).abs() < 1.0e-7);
+
+        let mut motors = [object];
+        let mut points = [WorldPoint::new(far, 1.0, far)];
+        shift_origin(&mut motors, &mut points, WorldPoint::new(far, 0.0, far));
+        assert!(
+            motors[0]
+                .translation_euler()
+                .dist(WorldPoint::new(0.125, 2.0, 0.5))
+                < 1.0e-9
+        );
+        assert_eq!(points[0], WorldPoint::new(0.0, 1.0, 0.0));
+
+        //Products of screw motors should match applying them one after the other
+        let point = WorldPoint::new(0.5, -1.0, 2.0);
+        for a in sample_motors::<4>().map(WorldMotor::from) {
+            for b in sample_motors::<4>().map(WorldMotor::from) {
+                let expected = b.transform(a.transform(point));
+                assert!(a.combine(b).transform(point).dist(expected) < 1.0e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_math() {
+        use fixed::Fixed;
+
+        for v in [
+            -7.0, -3.0, -1.0, -0.25, 0.0, 0.5, 1.0, 1.5, 2.5, 3.1, 4.0, 10.0,
+        ] {
+            let f = Fixed::from_f64(v);
+            assert!((f.sin().to_f64() - v.sin()).abs() < 1.0e-8, "sin {v}");
+            assert!((f.cos().to_f64() - v.cos()).abs() < 1.0e-8, "cos {v}");
+            if v >= 0.0 {
+                assert!((f.sqrt().to_f64() - v.sqrt()).abs() < 1.0e-8, "sqrt {v}");
+            }
+        }
+        assert_eq!((Fixed::from_int(3) * Fixed::HALF).to_f64(), 1.5);
+        assert_eq!((Fixed::from_int(3) / Fixed::from_int(4)).to_f64(), 0.75);
+        assert_eq!(Fixed::from_int(4).sqrt(), Fixed::TWO);
+        assert_eq!(Fixed::ONE / Fixed::ZERO, Fixed(i64::MAX));
+        assert_eq!(-Fixed::ONE / Fixed::ZERO, Fixed(-i64::MAX));
+        assert_eq!(Fixed::ZERO / Fixed::ZERO, Fixed::ZERO);
+        let zero = fixed::FixedMotor::new(
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::ZERO,
+            Fixed::ZERO,
+        );
+        assert_eq!(zero.normalized(), zero);
+    }
+
+    #[test]
+    fn fixed_motor_matches_float() {
+        use fixed::{Fixed, FixedMotor, FixedPoint};
+
+        let axis = Direction::new(1.0, 2.0, -0.5).normalized();
+        let angle = 1.3;
+        let fixed_motor = FixedMotor::from_rotation_around_axis(
+            Fixed::from_float(axis.x),
+            Fixed::from_float(axis.y),
+            Fixed::from_float(axis.z),
+            Fixed::from_float(angle),
+        )
+        .combine(FixedMotor::from_translation(
+            Fixed::from_int(3),
+            Fixed::from_int(-2),
+            Fixed::HALF,
+        ));
+        let motor = Motor::from_rotation_around_axis(axis.x, axis.y, axis.z, angle)
+            .combine(Motor::from_translation(3.0, -2.0, 0.5));
+        let point = Point::from_position(1.0, 2.0, 3.0);
+
+        let transformed = Point::from(fixed_motor.transform(FixedPoint::from(point)));
+        assert!(transformed.is_close(motor.transform(point)));
+        assert!(Point::from(
+            fixed_motor
+                .inverse()
+                .transform(fixed_motor.transform(point.into()))
+        )
+        .is_close(point));
+        assert!(Motor::from(fixed_motor.normalized())
+            .transform(point)
+            .is_close(motor.transform(point)));
+
+        //Products of screw motors should match applying them one after the other
+        for a in sample_motors::<4>().map(FixedMotor::from) {
+            for b in sample_motors::<4>().map(FixedMotor::from) {
+                let expected = Point::from(b.transform(a.transform(point.into())));
+                assert!(Point::from(a.combine(b).transform(point.into())).is_close(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_is_bit_exact() {
+        use fixed::{Fixed, FixedMotor, FixedPoint};
+
+        //Pinned results; any change here breaks lockstep compatibility between builds
+        assert_eq!(Fixed::ONE.sin().to_bits(), 0xd76a_a479);
+        assert_eq!(Fixed::TWO.sqrt().to_bits(), 0x1_6a09_e667);
+
+        let motor = FixedMotor::from_rotation_around_axis(
+            Fixed::ZERO,
+            Fixed::ONE,
+            Fixed::ZERO,
+            Fixed::from_bits(0x1_0000_0001),
+        )
+        .combine(FixedMotor::from_translation(
+            Fixed::ONE,
+            Fixed::TWO,
+            Fixed::HALF,
+        ));
+        let p = motor.transform(FixedPoint::from_position(
+            Fixed::ONE,
+            Fixed::ONE,
+            Fixed::ONE,
+        ));
+        assert_eq!(
+            [p.x.to_bits(), p.y.to_bits(), p.z.to_bits()],
+            [0x2_61bb_e4f6, 0x3_0000_0002, 0x32e6_9c04]
+        );
+    }
+
+    #[test]
+    fn motor_exp_log() {
+        let point = Point::from_position(0.5, -1.0, 2.0);
+        let mut motors = sample_motors::<6>().to_vec();
+        motors.push(Motor::from_translation(1.0, 2.0, -3.0));
+        motors.push(Motor::from_euler_angles(0.3, -1.1, 0.2));
+        motors.push(Motor::from_euler_pos_and_rot(1.0, 0.0, 0.0, 0.0, 3.0, 0.0));
+        for m in motors {
+            let round_trip = Motor::exp(m.log());
+            assert!(round_trip.transform(point).is_close(m.transform(point)));
+        }
+        assert_eq!(
+            Motor::IDENTITY.log(),
+            Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+        );
+
+        //A quarter turn around the z parallel line through (1, 0, 0)
+        let pivot = Point::from_position(1.0, 0.0, 0.0);
+        let axis = pivot.join(Point::from_position(1.0, 0.0, 1.0));
+        let quarter = Line::new(
+            axis.vx * float_consts::FRAC_PI_2,
+            axis.vy * float_consts::FRAC_PI_2,
+            axis.vz * float_consts::FRAC_PI_2,
+            axis.mx * float_consts::FRAC_PI_2,
+            axis.my * float_consts::FRAC_PI_2,
+            axis.mz * float_consts::FRAC_PI_2,
+        );
+        assert!(Motor::exp(quarter)
+            .transform(Point::from_position(2.0, 0.0, 0.0))
+            .is_close(Point::from_position(1.0, 1.0, 0.0)));
+    }
+
+    fn twist_between(a: Motor, b: Motor, h: Float) -> [Float; 6] {
+        let l = a.inverse().combine(b).log();
+        [l.vx, l.vy, l.vz, l.mx, l.my, l.mz].map(|v| v / h)
+    }
+    fn basis_twist(k: usize, h: Float) -> Line {
+        let mut v = [0.0; 6];
+        v[k] = h;
+        Line::new(v[0], v[1], v[2], v[3], v[4], v[5])
+    }
+
+    #[test]
+    fn jacobians_match_finite_differences() {
+        let h = 1e-2;
+        let [a, b] = sample_motors::<3>()[1..].try_into().unwrap();
+        let point = Point::from_position(0.5, -1.0, 2.0);
+
+        let jt = a.transform_jacobian(point);
+        let (ja, jb) = a.combine_jacobians(b);
+        for k in 0..6 {
+            let delta = Motor::exp(basis_twist(k, h));
+            let q0 = a.transform(point);
+            let q1 = a.combine(delta).transform(point);
+            let d = [(q1.x - q0.x) / h, (q1.y - q0.y) / h, (q1.z - q0.z) / h];
+            for i in 0..3 {
+                assert!((d[i] - jt[i][k]).abs() < 0.05);
+            }
+
+            let da = twist_between(a.combine(b), a.combine(delta).combine(b), h);
+            let db = twist_between(a.combine(b), a.combine(b.combine(delta)), h);
+            for i in 0..6 {
+                assert!((da[i] - ja[i][k]).abs() < 0.05);
+                assert!((db[i] - jb[i][k]).abs() < 0.05);
+            }
+        }
+
+        let rotation = a.transform_jacobian_point();
+        let moved = a.transform(Point::from_position(0.5 + h, -1.0, 2.0));
+        let q0 = a.transform(point);
+        for i in 0..3 {
+            let d = [moved.x - q0.x, moved.y - q0.y, moved.z - q0.z][i] / h;
+            assert!((d - rotation[i][0]).abs() < 0.01);
+        }
+
+        let params: [Float; 6] = [1.0, -2.0, 0.5, 0.3, 1.2, -0.4];
+        let je = Motor::from_euler_pos_and_rot_jacobian(
+            params[0], params[1], params[2], params[3], params[4], params[5],
+        );
+        let build =
+            |p: [Float; 6]| Motor::from_euler_pos_and_rot(p[0], p[1], p[2], p[3], p[4], p[5]);
+        for k in 0..6 {
+            let mut shifted = params;
+            shifted[k] += h;
+            let d = twist_between(build(params), build(shifted), h);
+            for i in 0..6 {
+                assert!((d[i] - je[i][k]).abs() < 0.05);
+            }
+        }
+    }
+
+    #[test]
+    fn orient3d_signs() {
+        use predicates::{coplanar, orient3d, Sign};
+
+        let a = Point::from_position(0.0, 0.0, 0.0);
+        let b = Point::from_position(1.0, 0.0, 0.0);
+        let c = Point::from_position(0.0, 1.0, 0.0);
+        assert_eq!(
+            orient3d(a, b, c, Point::from_position(0.3, 0.3, 1.0)),
+            Sign::Positive
+        );
+        assert_eq!(
+            orient3d(b, a, c, Point::from_position(0.3, 0.3, 1.0)),
+            Sign::Negative
+        );
+        assert_eq!(
+            orient3d(a, b, c, Point::from_position(0.5, 0.5, Float::MIN_POSITIVE)),
+            Sign::Positive
+        );
+
+        //Exactly coplanar, z = x + 2y, far from the origin
+        let offset: Float = 1048576.0;
+        let on_plane = |x: Float, y: Float| {
+            Point::from_position(offset + x, offset + y, 3.0 * offset + x + 2.0 * y)
+        };
+        let (a, b, c, d) = (
+            on_plane(1.0, 0.0),
+            on_plane(0.0, 3.0),
+            on_plane(-5.0, 2.0),
+            on_plane(7.0, -1.0),
+        );
+        assert_eq!(orient3d(a, b, c, d), Sign::Zero);
+        assert!(coplanar(a, b, c, d));
+        let lifted = Point::from_position(d.x, d.y, d.z + 1.0);
+        assert_eq!(orient3d(a, b, c, lifted), Sign::Positive);
+        assert!(!coplanar(a, b, c, lifted));
+
+        //Agrees with the plane through the first three points when far from degenerate
+        let points = sample_points::<6>();
+        for i in 0..3 {
+            let [a, b, c, d] = [points[i], points[i + 1], points[i + 2], points[i + 3]];
+            let v = a.join(b).join(c);
+            let side = v.x * d.x + v.y * d.y + v.z * d.z + v.w * d.w;
+            assert_eq!(
+                orient3d(a, b, c, d),
+                if side > 0.0 {
+                    Sign::Positive
+                } else {
+                    Sign::Negative
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn collinear_side_and_crossing() {
+        use predicates::{collinear, Sign};
+
+        let a = Point::from_position(1.0, 2.0, 3.0);
+        let b = Point::from_position(3.0, 5.0, 7.0);
+        assert!(collinear(a, b, Point::from_position(7.0, 11.0, 15.0)));
+        assert!(collinear(a, b, Point::new(14.0, 22.0, 30.0, 2.0)));
+        assert!(collinear(a, a, b));
+        assert!(!collinear(a, b, Point::from_position(7.0, 11.0, 15.001)));
+
+        let ground = Plane::new(0.0, 0.0, 1.0, 0.0);
+        assert_eq!(
+            ground.side_of(Point::from_position(4.0, 2.0, 1.0)),
+            Sign::Positive
+        );
+        assert_eq!(
+            ground.side_of(Point::from_position(4.0, 2.0, 0.0)),
+            Sign::Zero
+        );
+        assert_eq!(
+            ground.side_of(Point::from_position(4.0, 2.0, -Float::MIN_POSITIVE)),
+            Sign::Negative
+        );
+
+        let x_axis = Point::from_position(0.0, 0.0, 0.0).join(Point::from_position(1.0, 0.0, 0.0));
+        let above = Point::from_position(0.0, 0.0, 1.0).join(Point::from_position(0.0, 1.0, 1.0));
+        let below = Point::from_position(0.0, 0.0, -1.0).join(Point::from_position(0.0, 1.0, -1.0));
+        let through = Point::from_position(0.0, 0.0, 0.0).join(Point::from_position(0.0, 1.0, 0.0));
+        let parallel =
+            Point::from_position(0.0, 5.0, 2.0).join(Point::from_position(3.0, 5.0, 2.0));
+        assert_ne!(x_axis.crossing_sign(above), Sign::Zero);
+        assert_ne!(x_axis.crossing_sign(above), x_axis.crossing_sign(below));
+        assert_eq!(x_axis.crossing_sign(through), Sign::Zero);
+        assert_eq!(x_axis.crossing_sign(parallel), Sign::Zero);
+    }
+
+    #[test]
+    fn cross_ratios() {
+        use projective::{cross_ratio, harmonic_conjugate, plane_cross_ratio};
+
+        let on_x = |x: Float, w: Float| Point::new(x * w, 0.0, 0.0, w);
+        let [a, b, c, d] = [
+            on_x(0.0, 1.0),
+            on_x(1.0, 2.0),
+            on_x(2.0, 0.5),
+            on_x(3.0, 1.0),
+        ];
+        assert!((cross_ratio(a, b, c, d) - 4.0 / 3.0).abs() < 0.001);
+
+        let motor = Motor::from_euler_pos_and_rot(1.0, -2.0, 3.0, 0.4, 1.1, -0.7);
+        let moved = [a, b, c, d].map(|p| motor.transform(p));
+        assert!((cross_ratio(moved[0], moved[1], moved[2], moved[3]) - 4.0 / 3.0).abs() < 0.001);
+
+        let h = harmonic_conjugate(a, b, c);
+        assert!((cross_ratio(a, b, c, h) + 1.0).abs() < 0.001);
+        assert!(harmonic_conjugate(a, on_x(2.0, 1.0), b).w.abs() < 0.001);
+
+        //Planes through the z axis at different angles
+        let angles: [Float; 4] = [0.1, 0.5, 1.2, 2.0];
+        let [p, q, r, s] = angles.map(|t| Plane::new(t.cos(), t.sin(), 0.0, 0.0));
+        let expected = ((angles[2] - angles[0]).sin() * (angles[3] - angles[1]).sin())
+            / ((angles[3] - angles[0]).sin() * (angles[2] - angles[1]).sin());
+        assert!((plane_cross_ratio(p, q, r, s) - expected).abs() < 0.001);
+    }
+
+    #[test]
+    fn projective_incidence() {
+        use projective::{coaxial_planes, concurrent_lines, coplanar_lines};
+
+        let center = Point::from_position(1.0, 2.0, 3.0);
+        let through = |x: Float, y: Float, z: Float| center.join(Point::from_position(x, y, z));
+        let star = [
+            through(0.0, 0.0, 0.0),
+            through(4.0, 1.0, 0.0),
+            through(2.0, 7.0, -1.0),
+        ];
+        assert!(concurrent_lines(star[0], star[1], star[2]));
+        assert!(coplanar_lines(star[0], star[1]));
+
+        let corners = [
+            Point::from_position(0.0, 0.0, 0.0),
+            Point::from_position(3.0, 0.0, 0.0),
+            Point::from_position(0.0, 5.0, 0.0),
+        ];
+        let triangle = [0, 1, 2].map(|i| corners[i].join(corners[(i + 1) % 3]));
+        assert!(!concurrent_lines(triangle[0], triangle[1], triangle[2]));
+
+        let pencil = [
+            through(0.0, 2.0, 3.0),
+            through(1.0, 5.0, 3.0),
+            through(3.0, -1.0, 3.0),
+        ];
+        assert!(concurrent_lines(pencil[0], pencil[1], pencil[2]));
+
+        let up = |x: Float, y: Float| {
+            Point::from_position(x, y, 0.0).join(Point::from_position(x, y, 1.0))
+        };
+        assert!(concurrent_lines(up(0.0, 0.0), up(1.0, 0.0), up(0.0, 1.0)));
+        let skew = Point::from_position(0.0, 0.0, 1.0).join(Point::from_position(0.0, 1.0, 1.0));
+        assert!(!coplanar_lines(triangle[0], skew));
+        assert!(!concurrent_lines(triangle[0], triangle[1], skew));
+
+        let z_axis = Plane::new(1.0, 0.0, 0.0, 0.0).meet(Plane::new(0.0, 1.0, 0.0, 0.0));
+        assert!(lines_close(z_axis, Line::new(0.0, 0.0, 1.0, 0.0, 0.0, 0.0)));
+        assert!(z_axis
+            .meet(Plane::new(0.0, 0.0, 1.0, -2.0))
+            .is_close(Point::from_position(0.0, 0.0, 2.0)));
+        assert!(coaxial_planes(
+            Plane::new(1.0, 0.0, 0.0, 0.0),
+            Plane::new(0.0, 1.0, 0.0, 0.0),
+            Plane::new(1.0, 1.0, 0.0, 0.0)
+        ));
+        assert!(coaxial_planes(
+            Plane::new(1.0, 0.0, 0.0, 0.0),
+            Plane::new(1.0, 0.0, 0.0, -1.0),
+            Plane::new(2.0, 0.0, 0.0, 5.0)
+        ));
+        assert!(!coaxial_planes(
+            Plane::new(1.0, 0.0, 0.0, 0.0),
+            Plane::new(0.0, 1.0, 0.0, 0.0),
+            Plane::new(0.0, 0.0, 1.0, 0.0)
+        ));
+    }
+
+    #[test]
+    fn collineation_matches_motor() {
+        use collineation::Collineation;
+
+        let motor = Motor::from_euler_pos_and_rot(1.0, -2.0, 3.0, 0.4, 1.1, -0.7);
+        let c = Collineation::from(motor);
+        let point = Point::from_position(0.5, -1.0, 2.0);
+        let plane = Plane::new(0.0, 0.6, 0.8, -2.0);
+        let line = point.join(Point::from_position(3.0, 1.0, -1.0));
+
+        assert!(c.transform(point).is_close(motor.transform(point)));
+        assert!(c
+            .transform_plane(plane)
+            .normal()
+            .is_close(motor.transform_plane(plane).normal()));
+        assert!((c.transform_plane(plane).w - motor.transform_plane(plane).w).abs() < 0.001);
+        assert!(lines_close(
+            c.transform_line(line),
+            motor.transform_line(line)
+        ));
+
+        let other = Motor::from_translation(0.0, 4.0, 1.0);
+        assert!(c
+            .combine_motor(other)
+            .transform(point)
+            .is_close(motor.combine(other).transform(point)));
+        assert!(motor
+            .combine_collineation(Collineation::scale(2.0, 1.0, 1.0))
+            .transform(point)
+            .is_close({
+                let q = motor.transform(point);
+                Point::new(2.0 * q.x, q.y, q.z, q.w)
+            }));
+        assert!(c.inverse().transform(c.transform(point)).is_close(point));
+        assert!((c.determinant() - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn collineation_projections() {
+        use collineation::Collineation;
+
+        let proj = Collineation::perspective(float_consts::FRAC_PI_2, 2.0, 0.5, 100.0);
+        let near = proj
+            .transform(Point::from_position(0.5, 0.5, -0.5))
+            .scaled();
+        let far = proj
+            .transform(Point::from_position(0.0, 0.0, -100.0))
+            .scaled();
+        assert!(near.is_close(Point::from_position(0.5, 1.0, 0.0)));
+        assert!(far.is_close(Point::from_position(0.0, 0.0, 1.0)));
+
+        let ortho = Collineation::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0);
+        assert!(ortho
+            .transform(Point::from_position(2.0, -1.0, -6.0))
+            .is_close(Point::from_position(1.0, -1.0, 0.5)));
+
+        //Incidence survives a general projective map
+        let map = Collineation::shear(0.5, 0.0, -0.3, 0.2, 0.1, 0.0)
+            .combine(Collineation::scale(2.0, 3.0, 0.5))
+            .combine(proj);
+        let [a, b, c] = [
+            Point::from_position(1.0, 2.0, -3.0),
+            Point::from_position(-2.0, 0.5, -4.0),
+            Point::from_position(0.0, 1.0, -8.0),
+        ];
+        let plane = a.join(b).join(c);
+        let moved_plane = map.transform_plane(plane);
+        for p in [a, b, c] {
+            let q = map.transform(p);
+            let side = moved_plane.x * q.x
+                + moved_plane.y * q.y
+                + moved_plane.z * q.z
+                + moved_plane.w * q.w;
+            assert!(side.abs() < 0.001);
+        }
+        let joined = map.transform(a).join(map.transform(b));
+        let moved_line = map.transform_line(a.join(b));
+        let scale = joined.vx / moved_line.vx;
+        assert!(lines_close(
+            joined,
+            Line::new(
+                moved_line.vx * scale,
+                moved_line.vy * scale,
+                moved_line.vz * scale,
+                moved_line.mx * scale,
+                moved_line.my * scale,
+                moved_line.mz * scale
+            )
+        ));
+    }
+
+    #[test]
+    fn camera_project_unproject() {
+        use camera::Camera;
+
+        //Standing at (0, 1, 5) and turned a quarter to the left, so looking down -X
+        let pose = Motor::from_euler_pos_and_rot(0.0, 1.0, 5.0, 0.0, float_consts::FRAC_PI_2, 0.0);
+        let camera = Camera::new(pose, float_consts::FRAC_PI_2, 800.0, 600.0, 0.5, 100.0);
+
+        let ahead = Point::from_position(-10.0, 1.0, 5.0);
+        let [x, y, _] = camera.project(ahead).unwrap();
+        assert!((x - 400.0).abs() < 0.01 && (y - 300.0).abs() < 0.01);
+        assert!(camera
+            .project(Point::from_position(10.0, 1.0, 5.0))
+            .is_none());
+
+        let forward = camera.unproject(400.0, 300.0);
+        assert!(forward
+            .direction()
+            .normalized()
+            .is_close(Direction::new(-1.0, 0.0, 0.0)));
+
+        //Up and to the right of the view
+        let target = Point::from_position(-4.0, 3.0, 4.0);
+        let [x, y, depth] = camera.project(target).unwrap();
+        assert!(x > 400.0 && y < 300.0 && depth > 0.0 && depth < 1.0);
+        assert!(camera.unproject(x, y).distance_to_point(target) < 0.01);
+        assert!(
+            camera
+                .unproject(x, y)
+                .direction()
+                .dot(target.scaled().to_direction_unchecked() - Direction::new(0.0, 1.0, 5.0))
+                > 0.0
+        );
+
+        let near = camera
+            .project(Point::from_position(-0.5, 1.0, 5.0))
+            .unwrap()[2];
+        let far = camera
+            .project(Point::from_position(-100.0, 1.0, 5.0))
+            .unwrap()[2];
+        assert!(near.abs() < 0.001 && (far - 1.0).abs() < 0.001);
+        let reversed = camera.with_reverse_z(true);
+        assert!((reversed.project(target).unwrap()[2] - (1.0 - depth)).abs() < 0.001);
+        assert!((camera.fov_y() - float_consts::FRAC_PI_2).abs() < 0.001);
+    }
+
+    #[test]
+    fn camera_matrices_match_projection() {
+        use camera::Camera;
+
+        let pose = Motor::from_euler_pos_and_rot(2.0, 1.0, 5.0, -0.3, 0.4, 0.1);
+        let target = Point::from_position(0.5, -0.5, -3.0);
+        for camera in [
+            Camera::from_focal_lengths(pose, 500.0, 520.0, 330.0, 230.0, 640.0, 480.0, 0.1, 50.0),
+            Camera::new(pose, 1.0, 640.0, 480.0, 0.1, 50.0).with_reverse_z(true),
+        ] {
+            let [x, y, depth] = camera.project(target).unwrap();
+            let clip = camera.view_projection_matrix().transform(target).scaled();
+            let ndc_x = 2.0 * x / camera.width - 1.0;
+            let ndc_y = 1.0 - 2.0 * y / camera.height;
+            assert!(clip.is_close(Point::from_position(ndc_x, ndc_y, depth)));
+            assert!(camera
+                .view_matrix()
+                .transform(target)
+                .is_close(camera.view_motor().transform(target)));
+        }
+    }
+
+    #[test]
+    fn frustum_culling() {
+        use frustum::{Containment, Frustum};
+
+        let frustum =
+            Frustum::perspective(Motor::IDENTITY, float_consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+        assert!(frustum.contains_point(Point::from_position(0.0, 0.0, -5.0)));
+        assert!(!frustum.contains_point(Point::from_position(0.0, 0.0, 5.0)));
+        assert!(!frustum.contains_point(Point::from_position(6.0, 0.0, -5.0)));
+        let corners = frustum.corners().unwrap();
+        assert!(corners[0].is_close(Point::from_position(-1.0, -1.0, -1.0)));
+        assert!(corners[7].is_close(Point::from_position(10.0, 10.0, -10.0)));
+
+        //A far plane parallel to the side edges puts its corners at infinity
+        let open = Frustum::new([
+            Plane::new(1.0, 0.0, 0.0, 1.0),
+            Plane::new(-1.0, 0.0, 0.0, 1.0),
+            Plane::new(0.0, 1.0, 0.0, 1.0),
+            Plane::new(0.0, -1.0, 0.0, 1.0),
+            Plane::new(0.0, 0.0, -1.0, -1.0),
+            Plane::new(1.0, 1.0, 0.0, 5.0),
+        ]);
+        assert_eq!(open.corners(), None);
+        assert!(open.intersects_frustum(&frustum));
+
+        let off_side = Point::from_position(6.0, 0.0, -5.0);
+        let ahead = Point::from_position(0.0, 0.0, -5.0);
+        assert_eq!(frustum.test_sphere(off_side, 0.5), Containment::Outside);
+        assert_eq!(
+            frustum.test_sphere(off_side, 2.0),
+            Containment::Intersecting
+        );
+        assert_eq!(frustum.test_sphere(ahead, 1.0), Containment::Inside);
+
+        let aabb = |x: Float| {
+            frustum.test_aabb(
+                Point::from_position(x - 1.0, -1.0, -6.0),
+                Point::from_position(x + 1.0, 1.0, -4.0),
+            )
+        };
+        assert_eq!(aabb(0.0), Containment::Inside);
+        assert_eq!(aabb(5.0), Containment::Intersecting);
+        assert_eq!(aabb(20.0), Containment::Outside);
+
+        let obb = |x: Float| {
+            let pose = Motor::from_euler_pos_and_rot(x, 0.0, -5.0, 0.0, 0.8, 0.3);
+            frustum.test_obb(pose, Direction::new(1.0, 0.5, 2.0))
+        };
+        assert_eq!(obb(0.0), Containment::Inside);
+        assert_eq!(obb(4.0), Containment::Intersecting);
+        assert_eq!(obb(20.0), Containment::Outside);
+    }
+
+    #[test]
+    fn frustum_construction_and_overlap() {
+        use camera::Camera;
+        use frustum::Frustum;
+
+        let pose = Motor::from_euler_pos_and_rot(2.0, 1.0, 5.0, -0.3, 0.4, 0.1);
+        let moved = Frustum::perspective(pose, 1.0, 1.5, 0.5, 20.0);
+        let transformed =
+            Frustum::perspective(Motor::IDENTITY, 1.0, 1.5, 0.5, 20.0).transformed(pose);
+        let camera = Camera::new(pose, 1.0, 300.0, 200.0, 0.5, 20.0);
+        for f in [
+            transformed,
+            Frustum::from_camera(&camera),
+            Frustum::from_camera(&camera.with_reverse_z(true)),
+        ] {
+            for (a, b) in f.planes.iter().zip(moved.planes) {
+                assert!(a.normal().is_close(b.normal()) && (a.w - b.w).abs() < 0.01);
+            }
+        }
+
+        //Shadow cascades splitting one view, and a frustum looking the other way
+        let near = Frustum::perspective(Motor::IDENTITY, 1.0, 1.0, 0.5, 5.0);
+        let far = Frustum::perspective(Motor::IDENTITY, 1.0, 1.0, 4.0, 30.0);
+        let behind = Frustum::perspective(
+            Motor::from_euler_pos_and_rot(0.0, 0.0, 1.0, 0.0, float_consts::PI, 0.0),
+            1.0,
+            1.0,
+            0.5,
+            30.0,
+        );
+        assert!(near.intersects_frustum(&far));
+        assert!(!near.intersects_frustum(&behind));
+        assert!(!behind.intersects_frustum(&far));
+    }
+
+    #[test]
+    fn arcball_rotation() {
+        use controller::Arcball;
+
+        let arcball = Arcball::new(800.0, 600.0);
+        let still = arcball.rotation((400.0, 300.0), (400.0, 300.0));
+        assert!(still
+            .transform(Point::from_position(1.0, 2.0, 3.0))
+            .is_close(Point::from_position(1.0, 2.0, 3.0)));
+
+        //Dragging right turns the front of the sphere to the right
+        let (from, to) = ((400.0, 300.0), (550.0, 250.0));
+        let turn = arcball.rotation(from, to);
+        let front = turn.transform_direction(Direction::BACK);
+        assert!(front.x > 0.0 && front.y > 0.0);
+        assert!(turn
+            .transform_direction(arcball.sphere_point(from.0, from.1))
+            .is_close(arcball.sphere_point(to.0, to.1)));
+
+        //Long interactions stay rigid
+        let mut orientation = Motor::IDENTITY;
+        for i in 0..2000 {
+            let t = i as Float * 0.01;
+            orientation = arcball.drag(
+                orientation,
+                (400.0 + 300.0 * t.cos(), 300.0 + 250.0 * t.sin()),
+                (
+                    400.0 + 310.0 * (t + 0.3).cos(),
+                    300.0 + 240.0 * (t + 0.3).sin(),
+                ),
+            );
+        }
+        assert!((orientation.weight_norm() - 1.0).abs() < 0.0001);
+        let d = orientation.transform_direction(Direction::new(0.6, 0.0, 0.8));
+        assert!((d.magnitude() - 1.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn orbit_and_fly_cameras() {
+        use controller::{FlyCamera, OrbitCamera};
+
+        let target = Point::from_position(1.0, 2.0, 3.0);
+        let mut orbit = OrbitCamera::new(target, 10.0);
+        assert!(orbit
+            .pose()
+            .translation_euler()
+            .is_close(Point::from_position(1.0, 2.0, 13.0)));
+
+        orbit.rotate(0.7, 0.5);
+        orbit.rotate(0.0, 10.0);
+        assert!(orbit.pitch < float_consts::FRAC_PI_2);
+        orbit.pitch = 0.5;
+        let pose = orbit.pose();
+        let eye = pose.translation_euler();
+        assert!((eye.dist(target) - 10.0).abs() < 0.001);
+        assert!(eye.y > target.y);
+        let to_target =
+            (target.to_direction_unchecked() - eye.to_direction_unchecked()).normalized();
+        assert!(pose
+            .transform_direction(Direction::FORWARD)
+            .is_close(to_target));
+
+        orbit.pan(0.1, 0.0);
+        assert!((orbit.target.dist(target) - 1.0).abs() < 0.001);
+        orbit.dolly(0.5);
+        assert!((orbit.pose().translation_euler().dist(orbit.target) - 5.0).abs() < 0.001);
+        orbit.dolly(0.0);
+        assert!(orbit.distance > 0.0);
+
+        let mut fly = FlyCamera::new(Point::from_position(0.0, 1.0, 0.0));
+        fly.rotate(float_consts::FRAC_PI_2, 0.0);
+        fly.move_local(2.0, 0.0, 1.0);
+        assert!(fly.position.is_close(Point::from_position(-2.0, 2.0, 0.0)));
+        fly.rotate(4.0 * float_consts::TAU, 0.0);
+        assert!((fly.yaw - float_consts::FRAC_PI_2).abs() < 0.001);
+    }
+
+    #[test]
+    fn hierarchy_world_motors() {
+        use hierarchy::Hierarchy;
+
+        let mut h = Hierarchy::new();
+        let body = Motor::from_euler_pos_and_rot(0.0, 1.0, 0.0, 0.0, 0.5, 0.0);
+        let arm = Motor::from_euler_pos_and_rot(1.0, 0.0, 0.0, 0.0, 0.0, 0.8);
+        let hand = Motor::from_translation(0.0, 2.0, 0.0);
+        let root = h.add(body, None);
+        let upper = h.add(arm, Some(root));
+        let tip = h.add(hand, Some(upper));
+        let other = h.add(arm, Some(root));
+        h.update();
+
+        let p = Point::from_position(0.1, 0.2, 0.3);
+        let nested = body.transform(arm.transform(hand.transform(p)));
+        assert!(h.point_to_world(tip, p).is_close(nested));
+        assert!(h
+            .world(tip)
+            .transform(p)
+            .is_close(hand.combine(arm).combine(body).transform(p)));
+        assert!(h.point_to_local(tip, nested).is_close(p));
+
+        //Only the changed subtree moves
+        let sibling = h.world(other);
+        h.set_local(upper, Motor::IDENTITY);
+        h.update();
+        assert!(h
+            .point_to_world(tip, p)
+            .is_close(body.transform(hand.transform(p))));
+        assert_eq!(h.world(other), sibling);
+
+        let target = Motor::from_translation(5.0, 0.0, 0.0);
+        h.set_world(tip, target);
+        h.update();
+        assert!(h.world(tip).transform(p).is_close(target.transform(p)));
+        let local = Motor::from_translation(0.0, 0.0, 1.0);
+        assert!(h
+            .world_to_local(tip, h.local_to_world(tip, local))
+            .transform(p)
+            .is_close(local.transform(p)));
+    }
+
+    #[test]
+    fn hierarchy_reparent() {
+        use hierarchy::{Hierarchy, HierarchyError};
+
+        let mut h = Hierarchy::new();
+        let a = h.add(
+            Motor::from_euler_pos_and_rot(1.0, 0.0, 0.0, 0.3, 0.0, 0.0),
+            None,
+        );
+        let b = h.add(
+            Motor::from_euler_pos_and_rot(0.0, 2.0, 0.0, 0.0, 0.7, 0.0),
+            Some(a),
+        );
+        let c = h.add(Motor::from_translation(0.0, 0.0, 3.0), Some(b));
+        let d = h.add(
+            Motor::from_euler_pos_and_rot(-4.0, 0.0, 1.0, 0.0, 0.0, 1.2),
+            None,
+        );
+        h.update();
+
+        let p = Point::from_position(0.5, -0.5, 1.0);
+        let before = [b, c].map(|n| h.point_to_world(n, p));
+        h.reparent(b, Some(d)).unwrap();
+        assert_eq!(h.parent(b), Some(d));
+        for (n, q) in [b, c].iter().zip(before) {
+            assert!(h.point_to_world(*n, p).is_close(q));
+        }
+        let order = h.order().to_vec();
+        let position = |n| order.iter().position(|o| *o == n).unwrap();
+        assert!(position(d) < position(b) && position(b) < position(c));
+
+        assert_eq!(h.reparent(d, Some(c)), Err(HierarchyError::Cycle));
+        assert_eq!(h.reparent(b, Some(b)), Err(HierarchyError::Cycle));
+        h.reparent(b, None).unwrap();
+        assert!(h.point_to_world(c, p).is_close(before[1]));
+    }
+
+    #[test]
+    fn transform_buffer_lookup() {
+        use tf::{Extrapolation, TransformBuffer};
+
+        let mut tf = TransformBuffer::new(Extrapolation::Error);
+        let odom_0 = Motor::from_euler_pos_and_rot(0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let odom_1 = Motor::from_euler_pos_and_rot(2.0, 0.0, 0.0, 0.0, 0.0, 0.8);
+        tf.set_transform("map", "odom", 0.0, odom_0).unwrap();
+        tf.set_transform("map", "odom", 1.0, odom_1).unwrap();
+        let base = Motor::from_euler_pos_and_rot(0.0, 1.0, 0.0, 0.0, 0.4, 0.0);
+        tf.set_transform("odom", "base", 0.0, base).unwrap();
+        tf.set_transform("odom", "base", 2.0, base).unwrap();
+        let camera = Motor::from_euler_pos_and_rot(0.1, 0.0, 0.5, 0.2, 0.0, 0.0);
+        tf.set_static_transform("base", "camera", camera).unwrap();
+        let wheel = Motor::from_translation(0.0, -0.3, 0.0);
+        tf.set_static_transform("base", "wheel", wheel).unwrap();
+        assert_eq!(tf.parent("camera"), Some("base"));
+
+        //Halfway along the odom screw, then through the static camera mount
+        let odom_half = odom_0.interpolate(odom_1, 0.5);
+        let p = Point::from_position(0.3, -0.2, 1.5);
+        let expected = odom_half.transform(base.transform(camera.transform(p)));
+        let camera_in_map = tf.lookup("map", "camera", 0.5).unwrap();
+        assert!(camera_in_map.transform(p).is_close(expected));
+        let map_in_camera = tf.lookup("camera", "map", 0.5).unwrap();
+        assert!(map_in_camera.transform(expected).is_close(p));
+        assert!(tf
+            .lookup("camera", "camera", 0.5)
+            .unwrap()
+            .transform(p)
+            .is_close(p));
+
+        //Siblings only go up to their common parent, so odom's history is not needed
+        let wheel_in_camera = tf.lookup("camera", "wheel", 1.5).unwrap();
+        let expected = camera.inverse().transform(wheel.transform(p));
+        assert!(wheel_in_camera.transform(p).is_close(expected));
+
+        //Halfway between samples moves at constant speed along the screw
+        let mid = odom_0.interpolate(odom_1, 0.5);
+        let quarter = odom_0.interpolate(odom_1, 0.25);
+        assert!(quarter
+            .interpolate(odom_1, 1.0 / 3.0)
+            .transform(p)
+            .is_close(mid.transform(p)));
+    }
+
+    #[test]
+    fn transform_buffer_extrapolation() {
+        use tf::{Extrapolation, TfError, TransformBuffer};
+
+        let mut tf = TransformBuffer::new(Extrapolation::Error);
+        let a = Motor::from_translation(1.0, 0.0, 0.0);
+        let b = Motor::from_euler_pos_and_rot(2.0, 0.0, 0.0, 0.0, 0.0, 0.5);
+        tf.set_transform("world", "robot", 1.0, a).unwrap();
+        tf.set_transform("world", "robot", 2.0, b).unwrap();
+        let p = Point::from_position(0.0, 1.0, 0.0);
+
+        assert_eq!(
+            tf.lookup("world", "robot", 3.0),
+            Err(TfError::Extrapolation {
+                frame: "robot".to_owned(),
+                time: 3.0
+            })
+        );
+        tf.extrapolation = Extrapolation::Clamp;
+        let clamped = tf.lookup("world", "robot", 3.0).unwrap();
+        assert!(clamped.transform(p).is_close(b.transform(p)));
+        let clamped = tf.lookup("world", "robot", 0.0).unwrap();
+        assert!(clamped.transform(p).is_close(a.transform(p)));
+        tf.extrapolation = Extrapolation::Linear;
+        let linear = tf.lookup("world", "robot", 3.0).unwrap();
+        let expected = b.combine(a.inverse()).combine(b);
+        assert!(linear.transform(p).is_close(expected.transform(p)));
+
+        //Pruning keeps the sample needed to interpolate up to the cutoff
+        tf.set_transform("world", "robot", 3.0, a).unwrap();
+        tf.prune_before(2.5);
+        tf.extrapolation = Extrapolation::Error;
+        assert!(tf.lookup("world", "robot", 2.5).is_ok());
+        assert!(tf.lookup("world", "robot", 1.5).is_err());
+
+        assert_eq!(
+            tf.set_transform("robot", "world", 0.0, a),
+            Err(TfError::Cycle("robot".to_owned(), "world".to_owned()))
+        );
+        assert_eq!(
+            tf.lookup("world", "lidar", 2.0),
+            Err(TfError::UnknownFrame("lidar".to_owned()))
+        );
+        tf.set_transform("other", "lidar", 2.0, a).unwrap();
+        assert_eq!(
+            tf.lookup("robot", "lidar", 2.0),
+            Err(TfError::NotConnected(
+                "robot".to_owned(),
+                "lidar".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn frame_tagged_motors() {
+        use frame::{FrameMotor, Framed, Local, World};
+        enum Shifted {}
+
+        //The chain from motor_combination_bug, where each step has to start in the frame the
+        //previous one ends in
+        let translate =
+            FrameMotor::<Local, Shifted>::new(Motor::from_translation(-1.0, -1.0, -1.0));
+        let rotate = FrameMotor::<Shifted, World>::new(Motor::from_rotation_around_axis(
+            0.0,
+            1.0,
+            0.0,
+            -float_consts::PI * 0.5,
+        ));
+        let local_to_world: FrameMotor<Local, World> = translate.combine(rotate);
+        let p = Framed::<Point, Local>::new(Point::FORWARD);
+        let q: Framed<Point, World> = local_to_world.transform(p);
+        assert!(q.value.is_close(Point::from_position(2.0, -1.0, -1.0)));
+        assert!(rotate
+            .transform(translate.transform(p))
+            .value
+            .is_close(q.value));
+
+        let back: Framed<Point, Local> = local_to_world.inverse().transform(q);
+        assert!(Point::from(back).is_close(Point::FORWARD));
+        let identity = local_to_world.combine(local_to_world.inverse());
+        assert!(identity
+            .transform(p)
+            .value
+            .is_close(FrameMotor::<Local, Local>::IDENTITY.transform(p).value));
+        assert_eq!(
+            Motor::from(local_to_world),
+            translate.motor.combine(rotate.motor)
+        );
+
+        assert_eq!(
+            std::mem::size_of::<FrameMotor<Local, World>>(),
+            std::mem::size_of::<Motor>()
+        );
+        assert_eq!(
+            std::mem::size_of::<Framed<Point, World>>(),
+            std::mem::size_of::<Point>()
+        );
+    }
+
+    #[test]
+    fn convention_directions() {
+        use convention::{Axis, CoordinateSystem};
+
+        let native = CoordinateSystem::NATIVE;
+        assert_eq!(native.up(), Direction::UP);
+        assert_eq!(native.forward(), Direction::FORWARD);
+        assert_eq!(native.left(), Direction::LEFT);
+        assert!(native.is_right_handed() && CoordinateSystem::BLENDER.is_right_handed());
+        assert!(CoordinateSystem::ROS.is_right_handed());
+        assert!(!CoordinateSystem::UNREAL.is_right_handed());
+        assert!(!CoordinateSystem::UNITY.is_right_handed());
+        assert_eq!(
+            CoordinateSystem::new(Axis::PosX, Axis::NegX, Axis::PosZ),
+            None
+        );
+
+        for target in [
+            CoordinateSystem::BLENDER,
+            CoordinateSystem::UNITY,
+            CoordinateSystem::UNREAL,
+            CoordinateSystem::ROS,
+        ] {
+            let c = native.conversion_to(target);
+            assert_eq!(c.direction(Direction::UP), target.up());
+            assert_eq!(c.direction(Direction::RIGHT), target.right());
+            assert_eq!(c.direction(Direction::BACK), target.back());
+            assert_eq!(c.flips_handedness(), !target.is_right_handed());
+            assert_eq!(c.inverse(), target.conversion_to(native));
+        }
+    }
+
+    #[test]
+    fn convention_conversions() {
+        use convention::CoordinateSystem;
+
+        let m = Motor::from_euler_pos_and_rot(1.0, -2.0, 0.5, 0.3, -0.7, 1.1);
+        let a = Point::from_position(0.2, 1.5, -3.0);
+        let b = Point::from_position(-1.0, 0.4, 2.0);
+        let c = Point::from_position(0.7, -0.6, 0.1);
+        for target in [CoordinateSystem::BLENDER, CoordinateSystem::UNREAL] {
+            let conv = CoordinateSystem::NATIVE.conversion_to(target);
+            //Converting then transforming matches transforming then converting
+            let m2 = conv.motor(m);
+            assert!(m2
+                .transform(conv.point(a))
+                .is_close(conv.point(m.transform(a))));
+            let line = conv.line(a.join(b));
+            let expected = conv.point(a).join(conv.point(b));
+            assert!(line.direction().is_close(expected.direction()));
+            assert!(
+                Direction::new(line.mx, line.my, line.mz).is_close(Direction::new(
+                    expected.mx,
+                    expected.my,
+                    expected.mz
+                ))
+            );
+            let moved = m2.transform_line(line);
+            let expected = conv.line(m.transform_line(a.join(b)));
+            assert!(moved.direction().is_close(expected.direction()));
+            //Joins orient planes by handedness, while converted planes keep their sides
+            let plane = a.join(b).join(c);
+            let converted = conv.plane(plane);
+            for p in [a, b, c] {
+                assert!(converted.signed_distance(conv.point(p)).abs() < 1e-4);
+            }
+            let d = Point::from_position(3.0, 3.0, 3.0);
+            assert_eq!(
+                converted.signed_distance(conv.point(d)) > 0.0,
+                plane.signed_distance(d) > 0.0
+            );
+
+            //A quarter turn carrying right to up still does so after conversion
+            let rotor = Motor::from_rotation_around_axis(0.0, 0.0, 1.0, float_consts::FRAC_PI_2);
+            assert!(rotor
+                .transform_direction(Direction::RIGHT)
+                .is_close(Direction::UP));
+            let rotor = conv.motor(rotor);
+            assert!(rotor
+                .transform_direction(target.right())
+                .is_close(target.up()));
+
+            let back = conv.inverse();
+            assert_eq!(back.motor(m2), m);
+            assert_eq!(back.point(conv.point(a)), a);
+        }
+    }
+
+    #[test]
+    fn point_track_interpolation() {
+        use animation::{Cursor, Interpolation, Key, PointTrack, WrapMode};
+
+        let a = Point::from_position(0.0, 0.0, 0.0);
+        let b = Point::from_position(2.0, 0.0, 0.0);
+        let c = Point::from_position(2.0, 4.0, 0.0);
+        let mut track = PointTrack::new(WrapMode::Clamp);
+        track.insert(Key::new(2.0, c, Interpolation::Linear));
+        track.insert(Key::new(0.0, a, Interpolation::Step));
+        track.insert(Key::cubic(
+            1.0,
+            b,
+            Direction::ZERO,
+            Direction::new(0.0, 8.0, 0.0),
+        ));
+        assert_eq!(track.range(), Some((0.0, 2.0)));
+
+        assert_eq!(track.sample(0.9), Some(a));
+        assert_eq!(track.sample(-1.0), Some(a));
+        assert_eq!(track.sample(3.0), Some(c));
+        assert!(track
+            .sample(1.5)
+            .unwrap()
+            .is_close(Point::from_position(2.0, 3.0, 0.0)));
+        //Leaves the cubic key at its out tangent
+        let h = 1e-3;
+        let p = track.sample(1.0 + h).unwrap().scaled();
+        assert!((p.y / h - 8.0).abs() < 0.1);
+
+        track.wrap = WrapMode::Loop;
+        assert!(track
+            .sample(3.5)
+            .unwrap()
+            .is_close(track.sample(1.5).unwrap()));
+        assert!(track
+            .sample(-0.5)
+            .unwrap()
+            .is_close(track.sample(1.5).unwrap()));
+
+        let mut cursor = Cursor::default();
+        for i in 0..40 {
+            let time = i as Float * 0.1;
+            let p = track.sample_with(&mut cursor, time).unwrap();
+            assert!(p.is_close(track.sample(time).unwrap()));
+        }
+    }
+
+    #[test]
+    fn motor_track_interpolation() {
+        use animation::{Interpolation, Key, MotorTrack, WrapMode};
+
+        let a = Motor::from_euler_pos_and_rot(0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let b = Motor::from_euler_pos_and_rot(1.0, 2.0, 0.0, 0.0, 1.2, 0.3);
+        let mut track = MotorTrack::from_keys(
+            vec![
+                Key::new(0.0, a, Interpolation::Linear),
+                Key::new(2.0, b, Interpolation::Linear),
+            ],
+            WrapMode::Clamp,
+        );
+        let p = Point::from_position(0.5, 0.5, -1.0);
+        let mid = track.sample(0.5).unwrap();
+        assert!(mid
+            .transform(p)
+            .is_close(a.interpolate(b, 0.25).transform(p)));
+
+        //Tangents matching the screw from a to b reproduce the linear blend
+        let delta = a.inverse().combine(b).log();
+        let rate = Line::new(
+            delta.vx * 0.5,
+            delta.vy * 0.5,
+            delta.vz * 0.5,
+            delta.mx * 0.5,
+            delta.my * 0.5,
+            delta.mz * 0.5,
+        );
+        track.insert(Key::cubic(0.0, a, rate, rate));
+        track.insert(Key::cubic(2.0, b, rate, rate));
+        for time in [0.3, 1.0, 1.7] {
+            let expected = a.interpolate(b, time * 0.5);
+            assert!(track
+                .sample(time)
+                .unwrap()
+                .transform(p)
+                .is_close(expected.transform(p)));
+        }
+        //Zero tangents ease in and out
+        track.insert(Key::new(0.0, a, Interpolation::Cubic));
+        let eased = track.sample(0.2).unwrap();
+        assert!(eased.transform(p).dist(p) < a.interpolate(b, 0.1).transform(p).dist(p));
+    }
+
+    #[test]
+    fn motor_spline_curves() {
+        use spline::{de_casteljau, de_casteljau_velocity, MotorSpline, SplineKind};
+
+        let motors = vec![
+            Motor::from_euler_pos_and_rot(0.0, 0.0, 0.0, 0.0, 0.0, 0.0),
+            Motor::from_euler_pos_and_rot(1.0, 0.5, 0.0, 0.2, 0.4, 0.0),
+            Motor::from_euler_pos_and_rot(2.0, 0.0, 1.0, 0.0, 0.9, 0.3),
+            Motor::from_euler_pos_and_rot(3.0, -1.0, 1.0, -0.3, 1.2, 0.1),
+            Motor::from_euler_pos_and_rot(3.5, -1.0, 2.0, 0.0, 1.5, 0.0),
+        ];
+        let p = Point::from_position(0.3, -0.4, 0.8);
+        let close = |a: Motor, b: Motor| a.transform(p).is_close(b.transform(p));
+
+        //Control motors on one screw keep the whole curve on that screw
+        let screw = Motor::from_euler_pos_and_rot(0.0, 0.0, 2.0, 0.0, 0.0, 1.0);
+        let on_screw = [0.0, 0.2, 0.7, 1.0].map(|t| Motor::IDENTITY.interpolate(screw, t));
+        //The scalar Bezier through 0, 0.2, 0.7 and 1 is at 0.4625 halfway
+        let error = de_casteljau(&on_screw, 0.5).log() - screw.log() * 0.4625;
+        assert!(error.weight_norm() < 1e-4 && error.bulk_norm() < 1e-4);
+
+        let catmull = MotorSpline::new(SplineKind::CatmullRom, motors.clone());
+        assert_eq!(catmull.segments(), 4);
+        for (i, m) in motors.iter().enumerate() {
+            assert!(close(catmull.sample(i as Float), *m));
+        }
+        let bspline = MotorSpline::new(SplineKind::BSpline, motors.clone());
+        assert_eq!(bspline.segments(), 2);
+        //Segments meet with matching velocities
+        for spline in [&catmull, &bspline] {
+            let (before, after) = (spline.velocity(0.99999), spline.velocity(1.0));
+            assert!((before - after).weight_norm() < 1e-3);
+            assert!((before - after).bulk_norm() < 1e-3);
+        }
+
+        //Velocities match central differences along the curve, in the frame of the curve at t
+        let h = 1e-3;
+        let central = |sample: &dyn Fn(Float) -> Motor, t: Float| {
+            let inverse = sample(t).inverse();
+            (inverse.combine(sample(t + h)).log() - inverse.combine(sample(t - h)).log())
+                * (0.5 / h)
+        };
+        let controls = [motors[0], motors[2], motors[3], motors[4]];
+        for t in [0.2, 0.5, 0.8] {
+            let error =
+                de_casteljau_velocity(&controls, t) - central(&|t| de_casteljau(&controls, t), t);
+            assert!(error.weight_norm() < 1e-3 && error.bulk_norm() < 1e-3);
+        }
+        for spline in [&catmull, &bspline] {
+            for t in [0.3, 1.5, 1.9] {
+                let error = spline.velocity(t) - central(&|t| spline.sample(t), t);
+                assert!(error.weight_norm() < 1e-3 && error.bulk_norm() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn spline_arc_length() {
+        use spline::{Measure, MotorSpline, SplineKind};
+
+        //Uneven spacing still gives even steps after reparameterizing
+        let motors = vec![
+            Motor::from_translation(0.0, 0.0, 0.0),
+            Motor::from_translation(1.0, 0.0, 0.0),
+            Motor::from_translation(5.0, 0.0, 0.0),
+            Motor::from_translation(6.0, 0.0, 0.0),
+        ];
+        let spline = MotorSpline::new(SplineKind::CatmullRom, motors);
+        let table = spline.arc_length_table(256, Measure::Distance);
+        assert!((table.total() - 6.0).abs() < 1e-3);
+        for i in 0..=6 {
+            let t = table.param_at(i as Float);
+            let x = spline.sample(t).transform(Point::ZERO).scaled().x;
+            assert!((x - i as Float).abs() < 0.01);
+        }
+        assert_eq!(table.param_at(-1.0), 0.0);
+        assert_eq!(table.param_at_fraction(2.0), 3.0);
+
+        let turn = MotorSpline::new(
+            SplineKind::CatmullRom,
+            vec![
+                Motor::IDENTITY,
+                Motor::from_rotation_around_axis(0.0, 1.0, 0.0, 1.0),
+            ],
+        );
+        let table = turn.arc_length_table(64, Measure::Angle);
+        assert!((table.total() - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn motor_blending() {
+        use blend::{blend, mean};
+
+        let a = Motor::from_euler_pos_and_rot(1.0, 0.0, 0.0, 0.0, 0.2, 0.0);
+        let b = Motor::from_euler_pos_and_rot(1.2, 0.3, 0.0, 0.1, 0.3, 0.0);
+        let flipped = Motor::new(-b.vx, -b.vy, -b.vz, -b.vw, -b.mx, -b.my, -b.mz, -b.mw);
+        let p = Point::from_position(0.2, 0.4, -1.0);
+        let halfway = a.interpolate(b, 0.5).transform(p);
+        let blended = blend(&[(a, 1.0), (flipped, 1.0)]).unwrap();
+        assert!(blended.transform(p).is_close(halfway));
+        let blended = blend(&[(a, 1.0), (b, 3.0)]).unwrap();
+        assert!(blended
+            .transform(p)
+            .is_close(a.interpolate(b, 0.75).transform(p)));
+        assert_eq!(blend(&[]), None);
+        assert_eq!(blend(&[(a, 1.0), (a, -1.0)]), None);
+
+        //Widely spread motors, where the blend is visibly off
+        let center = Motor::from_euler_pos_and_rot(0.0, 1.0, 0.0, 0.0, 0.5, 0.0);
+        let offset = Motor::from_euler_pos_and_rot(3.0, 0.0, 1.0, 0.0, 1.3, 0.4);
+        let motors = [
+            (offset.combine(center), 1.0),
+            (offset.inverse().combine(center), 1.0),
+        ];
+        let result = mean(&motors, 1e-5, 50).unwrap();
+        assert!(result.converged && result.iterations < 50);
+        assert!(result.residual <= 1e-5);
+        let halfway = motors[0].0.interpolate(motors[1].0, 0.5);
+        assert!(result.motor.transform(p).is_close(halfway.transform(p)));
+        let inverse = result.motor.inverse();
+        let sum = inverse.combine(motors[0].0).log() + inverse.combine(motors[1].0).log();
+        assert!(sum.weight_norm() < 1e-3 && sum.bulk_norm() < 1e-3);
+
+        let unconverged = mean(&motors, 1e-5, 0).unwrap();
+        assert!(!unconverged.converged && unconverged.iterations == 0);
+        assert_eq!(mean(&[(a, 0.0)], 1e-5, 10), None);
+    }
+
+    #[test]
+    fn skinning_matches_motor_transform() {
+        use skinning::{skin_directions, skin_motor, skin_points, Influences};
+
+        let bones = [
+            Motor::from_euler_pos_and_rot(0.0, 1.0, 0.0, 0.3, 0.0, 0.0),
+            Motor::from_euler_pos_and_rot(0.5, 0.0, -1.0, 0.0, 0.8, 0.2),
+            Motor::from_translation(0.0, 0.0, 2.0),
+        ];
+        let influences: Vec<Influences> = (0..7)
+            .map(|i| {
+                let w = i as Float / 6.0;
+                Influences::new([0, 1, 2, (i % 3) as u16], [1.0 - w, w * 0.5, w * 0.5, 0.0])
+            })
+            .collect();
+        let points: Vec<Point> = (0..7)
+            .map(|i| Point::from_position(i as Float * 0.3, 1.0 - i as Float * 0.2, 0.5))
+            .collect();
+        let mut skinned = vec![Point::ZERO; 7];
+        skin_points(&bones, &influences, &points, &mut skinned);
+        for ((s, p), i) in skinned.iter().zip(&points).zip(&influences) {
+            assert_eq!(
+                *s,
+                UnitMotor::new_unchecked(skin_motor(&bones, i)).transform(*p)
+            );
+        }
+        assert!(skinned[0].is_close(bones[0].transform(points[0])));
+        let normals = vec![Direction::UP; 7];
+        let mut skinned = vec![Direction::ZERO; 7];
+        skin_directions(&bones, &influences, &normals, &mut skinned);
+        assert!(skinned[0].is_close(bones[0].transform_direction(Direction::UP)));
+
+        //Either half of the double cover blends the same
+        let b = bones[1];
+        let flipped = [
+            bones[0],
+            Motor::new(-b.vx, -b.vy, -b.vz, -b.vw, -b.mx, -b.my, -b.mz, -b.mw),
+        ];
+        let i = Influences::new([0, 1, 0, 0], [0.5, 0.5, 0.0, 0.0]);
+        let p = Point::from_position(0.2, 0.2, 0.2);
+        assert!(skin_motor(&flipped, &i)
+            .transform(p)
+            .is_close(skin_motor(&bones, &i).transform(p)));
+        assert_eq!(
+            skin_motor(&bones, &Influences::new([2, 1, 0, 0], [0.0; 4])),
+            Motor::IDENTITY
+        );
+    }
+
+    #[test]
+    fn skinning_keeps_joint_volume() {
+        use skinning::{skin_motor, Influences};
+
+        //An elbow bent by a right angle around a joint at x = 1
+        let bend = Motor::from_translation(-1.0, 0.0, 0.0)
+            .combine(Motor::from_rotation_around_axis(
+                0.0,
+                0.0,
+                1.0,
+                float_consts::FRAC_PI_2,
+            ))
+            .combine(Motor::from_translation(1.0, 0.0, 0.0));
+        let bones = [Motor::IDENTITY, bend];
+        let halfway = Influences::new([0, 1, 0, 0], [0.5, 0.5, 0.0, 0.0]);
+        let p = Point::from_position(1.0, 0.5, 0.0);
+        let joint = Point::from_position(1.0, 0.0, 0.0);
+        let skinned = skin_motor(&bones, &halfway).transform(p).scaled();
+        assert!((skinned.dist(joint) - 0.5).abs() < 1e-4);
+        //Linear blending pulls the same vertex towards the joint
+        let (a, b) = (
+            bones[0].transform(p).scaled(),
+            bones[1].transform(p).scaled(),
+        );
+        let linear = Point::from_position((a.x + b.x) * 0.5, (a.y + b.y) * 0.5, 0.0);
+        assert!(linear.dist(joint) < 0.4);
+    }
+
+    #[test]
+    fn two_bone_ik() {
+        use ik::{two_bone, Chain, Joint};
+
+        let chain = Chain::new(
+            Motor::from_translation(0.0, 1.0, 0.0),
+            vec![
+                Joint::ball(Motor::IDENTITY),
+                Joint::ball(Motor::from_translation(1.0, 0.0, 0.0)),
+            ],
+            Point::from_position(1.0, 0.0, 0.0),
+        );
+        let rest = chain.rest_locals();
+        let target = Point::from_position(1.2, 1.8, 0.0);
+        let pole = Point::from_position(0.5, 1.0, 2.0);
+        let solution = two_bone(&chain, &rest, target, pole);
+        assert!(solution.converged && solution.error < 1e-4);
+        let worlds = chain.worlds(&solution.locals);
+        let elbow = worlds[1].transform(Point::ZERO).scaled();
+        let shoulder = Point::from_position(0.0, 1.0, 0.0);
+        assert!((elbow.dist(shoulder) - 1.0).abs() < 1e-4);
+        assert!(elbow.z > 0.1);
+
+        //Out of reach straightens the chain towards the target
+        let far = Point::from_position(0.0, 1.0, -5.0);
+        let solution = two_bone(&chain, &rest, far, pole);
+        assert!(!solution.converged && (solution.error - 3.0).abs() < 1e-3);
+        assert!(chain
+            .effector_position(&solution.locals)
+            .is_close(Point::from_position(0.0, 1.0, -2.0)));
+    }
+
+    #[test]
+    fn iterative_ik() {
+        use ik::{ccd, fabrik, Chain, Joint};
+
+        let bone = Motor::from_translation(0.0, 1.0, 0.0);
+        let chain = Chain::new(
+            Motor::IDENTITY,
+            vec![
+                Joint::ball(Motor::IDENTITY),
+                Joint::ball(bone),
+                Joint::ball(bone),
+            ],
+            Point::from_position(0.0, 1.0, 0.0),
+        );
+        let rest = chain.rest_locals();
+        let target = Point::from_position(1.5, 1.0, 0.8);
+        for solver in [ccd, fabrik] {
+            let solution = solver(&chain, &rest, target, 1e-3, 100);
+            assert!(solution.converged && solution.iterations > 0);
+            assert!(chain.effector_position(&solution.locals).dist(target) <= 1e-3);
+            //Joints only rotate, so bone lengths are kept
+            let worlds = chain.worlds(&solution.locals);
+            let a = worlds[1].transform(Point::ZERO).scaled();
+            let b = worlds[2].transform(Point::ZERO).scaled();
+            assert!((a.dist(b) - 1.0).abs() < 1e-4);
+        }
+
+        //A hinge around a line offset from the joint, stopped by its limit
+        let axis = Point::from_position(0.5, 0.0, 0.0).join(Point::from_position(0.5, 0.0, 1.0));
+        let hinge = Chain::new(
+            Motor::IDENTITY,
+            vec![Joint::hinge(Motor::IDENTITY, axis, -0.5, 1.0)],
+            Point::from_position(0.5, 1.0, 0.0),
+        );
+        let rest = hinge.rest_locals();
+        let angle: Float = 0.5;
+        let reachable = Point::from_position(0.5 - angle.sin(), angle.cos(), 0.0);
+        //Reaching it would take about 2 radians, past the limit of 1
+        let beyond = Point::from_position(-0.5, -0.5, 0.3);
+        let limit: Float = 1.0;
+        let stopped = Point::from_position(0.5 - limit.sin(), limit.cos(), 0.0);
+        for solver in [ccd, fabrik] {
+            let solution = solver(&hinge, &rest, reachable, 1e-4, 10);
+            assert!(solution.converged);
+            let solution = solver(&hinge, &rest, beyond, 1e-4, 10);
+            assert!(!solution.converged && solution.iterations == 10);
+            assert!(hinge.effector_position(&solution.locals).is_close(stopped));
+        }
+    }
+
+    #[test]
+    fn swing_twist_decomposition() {
+        let m = Motor::from_euler_pos_and_rot(1.0, 2.0, -0.5, 0.4, 0.9, -0.3);
+        let axis = Direction::new(0.0, 2.0, 0.0);
+        let (swing, twist) = m.swing_twist(axis);
+        let rotation = m.factor_rotation();
+        let recombined = twist.combine(swing);
+        for d in [Direction::RIGHT, Direction::UP, Direction::BACK] {
+            assert!(recombined
+                .transform_direction(d)
+                .is_close(rotation.transform_direction(d)));
+        }
+        //The twist turns around the axis, and the swing around a perpendicular axis
+        assert!(twist.vx.abs() < 1e-6 && twist.vz.abs() < 1e-6);
+        assert!(swing.vy.abs() < 1e-5);
+        assert!(swing
+            .transform_direction(Direction::UP)
+            .is_close(rotation.transform_direction(Direction::UP)));
+
+        let half_turn = Motor::from_rotation_around_axis(1.0, 0.0, 0.0, float_consts::PI);
+        let (swing, twist) = half_turn.swing_twist(Direction::UP);
+        assert_eq!(twist, Motor::IDENTITY);
+        assert!(swing
+            .transform_direction(Direction::UP)
+            .is_close(Direction::DOWN));
+    }
+
+    #[test]
+    fn swing_twist_limits() {
+        use ik::{ccd, Chain, Joint, JointKind};
+        use limits::JointLimit;
+
+        let cone = JointLimit::new(Direction::UP, Direction::RIGHT)
+            .with_cone(0.5)
+            .with_twist(-0.2, 0.4);
+        //A twist of 1 around the axis, then a swing of 1 around the X axis
+        let twist = Motor::from_rotation_around_axis(0.0, 1.0, 0.0, 1.0);
+        let swing = Motor::from_rotation_around_axis(1.0, 0.0, 0.0, 1.0);
+        let m = twist
+            .combine(swing)
+            .combine(Motor::from_translation(3.0, 0.0, 0.0));
+        let ([sx, sy], t) = cone.angles(m);
+        assert!((sx - 1.0).abs() < 1e-4 && sy.abs() < 1e-4 && (t - 1.0).abs() < 1e-4);
+        assert!(!cone.contains(m));
+
+        let clamped = cone.clamp(m);
+        let ([sx, sy], t) = cone.angles(clamped);
+        assert!((sx - 0.5).abs() < 1e-4 && sy.abs() < 1e-4 && (t - 0.4).abs() < 1e-4);
+        assert!(clamped
+            .transform(Point::ZERO)
+            .is_close(m.transform(Point::ZERO)));
+        let inside = Motor::from_rotation_around_axis(0.0, 0.0, 1.0, 0.3);
+        assert!(cone.contains(inside));
+        assert!(cone
+            .clamp(inside)
+            .transform(Point::UP)
+            .is_close(inside.transform(Point::UP)));
+
+        //Elliptical limits here allow more swing around the reference than across it
+        let ellipse = JointLimit::new(Direction::UP, Direction::RIGHT).with_ellipse(1.0, 0.25);
+        let around_x = Motor::from_rotation_around_axis(1.0, 0.0, 0.0, 0.5);
+        let around_z = Motor::from_rotation_around_axis(0.0, 0.0, 1.0, 0.5);
+        assert!(ellipse.contains(around_x) && !ellipse.contains(around_z));
+        let ([sx, sy], _) = ellipse.angles(ellipse.clamp(around_z));
+        assert!(sx.abs() < 1e-4 && (sy + 0.25).abs() < 1e-4);
+
+        //A zero semi-axis locks that axis instead of dividing by zero
+        let hinge = JointLimit::new(Direction::UP, Direction::RIGHT).with_ellipse(0.0, 0.25);
+        assert!(!hinge.contains(around_x) && !hinge.contains(around_z));
+        let ([sx, sy], _) = hinge.angles(hinge.clamp(around_x));
+        assert!(sx.abs() < 1e-4 && sy.abs() < 1e-4);
+        let ([sx, sy], _) = hinge.angles(hinge.clamp(around_z));
+        assert!(sx.abs() < 1e-4 && (sy + 0.25).abs() < 1e-4);
+        let small = Motor::from_rotation_around_axis(0.0, 0.0, 1.0, 0.1);
+        assert!(hinge.contains(small));
+
+        //IK stops at the cone
+        let chain = Chain::new(
+            Motor::IDENTITY,
+            vec![Joint {
+                rest: Motor::IDENTITY,
+                kind: JointKind::Limited(cone),
+            }],
+            Point::from_position(0.0, 1.0, 0.0),
+        );
+        let solution = ccd(
+            &chain,
+            &chain.rest_locals(),
+            Point::from_position(1.0, 0.0, 0.0),
+            1e-4,
+            5,
+        );
+        assert!(!solution.converged);
+        let tip = chain.effector_position(&solution.locals);
+        assert!((tip.y - (0.5 as Float).cos()).abs() < 1e-4);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn json_serde() {
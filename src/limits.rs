@@ -0,0 +1,112 @@
+use crate::{direction::Direction, line::Line, motor::Motor, Float};
+
+#[cfg(not(feature = "f64"))]
+use std::f32::consts as float_consts;
+#[cfg(feature = "f64")]
+use std::f64::consts as float_consts;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SwingLimit {
+    Free,
+    //Keeps the twist axis within an angle of its rest direction
+    Cone { max_angle: Float },
+    //Separate limits for swinging around the reference direction and around axis cross reference
+    Elliptical { max_x: Float, max_y: Float },
+}
+
+//Limits on a rotation split by Motor::swing_twist. Angles are in radians measured from the rest
+//pose, with twists counterclockwise looking against the axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JointLimit {
+    pub axis: Direction,
+    //The x direction of elliptical limits, perpendicular to the axis
+    pub reference: Direction,
+    pub swing: SwingLimit,
+    pub min_twist: Float,
+    pub max_twist: Float,
+}
+impl JointLimit {
+    pub fn new(axis: Direction, reference: Direction) -> Self {
+        let axis = axis.normalized();
+        Self {
+            axis,
+            reference: (reference - axis * reference.dot(axis)).normalized(),
+            swing: SwingLimit::Free,
+            min_twist: -float_consts::PI,
+            max_twist: float_consts::PI,
+        }
+    }
+    pub fn with_cone(mut self, max_angle: Float) -> Self {
+        self.swing = SwingLimit::Cone { max_angle };
+        self
+    }
+    pub fn with_ellipse(mut self, max_x: Float, max_y: Float) -> Self {
+        self.swing = SwingLimit::Elliptical { max_x, max_y };
+        self
+    }
+    pub fn with_twist(mut self, min_twist: Float, max_twist: Float) -> Self {
+        self.min_twist = min_twist;
+        self.max_twist = max_twist;
+        self
+    }
+
+    //The swing's rotation vector in reference and axis cross reference coordinates, and the twist
+    //angle. Both take the shorter way around
+    pub fn angles(&self, m: Motor) -> ([Float; 2], Float) {
+        let (swing, twist) = m.swing_twist(self.axis);
+        let (x, y) = (self.reference, self.axis.cross(self.reference));
+        let swing = swing.log().direction();
+        let twist = twist.log().direction();
+        ([swing.dot(x), swing.dot(y)], twist.dot(self.axis))
+    }
+    pub fn contains(&self, m: Motor) -> bool {
+        let (swing, twist) = self.angles(m);
+        let clamped = self.clamp_swing(swing);
+        clamped == swing && twist >= self.min_twist && twist <= self.max_twist
+    }
+    //Clamps the rotation of a motor into the limits, keeping its translation. Swings outside
+    //elliptical limits are pulled straight back towards the rest pose onto the ellipse, which is
+    //close to but not always the nearest allowed swing
+    pub fn clamp(&self, m: Motor) -> Motor {
+        let (translation, _) = m.factorize();
+        let (swing, twist) = self.angles(m);
+        let [sx, sy] = self.clamp_swing(swing);
+        let twist = twist.clamp(self.min_twist, self.max_twist);
+
+        let (x, y, a) = (self.reference, self.axis.cross(self.reference), self.axis);
+        let twist = Motor::exp(Line::new(a.x, a.y, a.z, 0.0, 0.0, 0.0) * twist);
+        let swing = x * sx + y * sy;
+        let swing = Motor::exp(Line::new(swing.x, swing.y, swing.z, 0.0, 0.0, 0.0));
+        twist.combine(swing).combine(translation)
+    }
+
+    fn clamp_swing(&self, [x, y]: [Float; 2]) -> [Float; 2] {
+        let scale = match self.swing {
+            SwingLimit::Free => 1.0,
+            SwingLimit::Cone { max_angle } => {
+                let angle = (x * x + y * y).sqrt();
+                if angle > max_angle {
+                    max_angle / angle
+                } else {
+                    1.0
+                }
+            }
+            //A zero semi-axis locks swinging around that axis, leaving a plain range on the other
+            SwingLimit::Elliptical { max_x, max_y } if max_x <= 0.0 || max_y <= 0.0 => {
+                let limit = |v: Float, max: Float| v.clamp(-max.max(0.0), max.max(0.0));
+                return [limit(x, max_x), limit(y, max_y)];
+            }
+            SwingLimit::Elliptical { max_x, max_y } => {
+                let r = ((x / max_x).powi(2) + (y / max_y).powi(2)).sqrt();
+                if r > 1.0 {
+                    1.0 / r
+                } else {
+                    1.0
+                }
+            }
+        };
+        [x * scale, y * scale]
+    }
+}
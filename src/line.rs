@@ -1,6 +1,8 @@
-use crate::{plane::Plane, point::Point, Float};
+use std::ops::{Add, Mul, Neg, Sub};
 
-#[derive(Debug, Clone, Copy)]
+use crate::{direction::Direction, plane::Plane, point::Point, Float};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[repr(C)]
@@ -24,6 +26,45 @@ impl Line {
         }
     }
 
+    pub fn direction(&self) -> Direction {
+        Direction::new(self.vx, self.vy, self.vz)
+    }
+
+    pub fn weight_norm(&self) -> Float {
+        (self.vx * self.vx + self.vy * self.vy + self.vz * self.vz).sqrt()
+    }
+    pub fn bulk_norm(&self) -> Float {
+        (self.mx * self.mx + self.my * self.my + self.mz * self.mz).sqrt()
+    }
+    //Scales to a unit direction and removes any violation of the Plücker condition
+    pub fn normalized(&self) -> Self {
+        let inv_norm = 1.0 / self.weight_norm();
+        let vx = self.vx * inv_norm;
+        let vy = self.vy * inv_norm;
+        let vz = self.vz * inv_norm;
+        let mx = self.mx * inv_norm;
+        let my = self.my * inv_norm;
+        let mz = self.mz * inv_norm;
+
+        let constraint = vx * mx + vy * my + vz * mz;
+
+        Self::new(
+            vx,
+            vy,
+            vz,
+            mx - constraint * vx,
+            my - constraint * vy,
+            mz - constraint * vz,
+        )
+    }
+
+    pub fn distance_to_point(&self, p: Point) -> Float {
+        let x = p.y * self.vz - p.z * self.vy - p.w * self.mx;
+        let y = p.z * self.vx - p.x * self.vz - p.w * self.my;
+        let z = p.x * self.vy - p.y * self.vx - p.w * self.mz;
+        (x * x + y * y + z * z).sqrt() / (self.weight_norm() * p.w.abs())
+    }
+
     pub fn join(&self, rhs: Point) -> Plane {
         Plane::new(
             self.vy * rhs.z - self.vz * rhs.y + self.mx * rhs.w,
@@ -33,6 +74,16 @@ impl Line {
         )
     }
 
+    //The point where the line crosses the plane, ideal if they are parallel
+    pub fn meet(&self, rhs: Plane) -> Point {
+        Point::new(
+            rhs.y * self.mz - rhs.z * self.my - rhs.w * self.vx,
+            rhs.z * self.mx - rhs.x * self.mz - rhs.w * self.vy,
+            rhs.x * self.my - rhs.y * self.mx - rhs.w * self.vz,
+            self.vx * rhs.x + self.vy * rhs.y + self.vz * rhs.z,
+        )
+    }
+
     pub fn expand(&self, rhs: Plane) -> Plane {
         Plane::new(
             self.vy * rhs.z - self.vz * rhs.y,
@@ -42,3 +93,42 @@ impl Line {
         )
     }
 }
+//Componentwise, for working with lines as twists
+impl Add<Line> for Line {
+    type Output = Self;
+    fn add(self, rhs: Line) -> Self::Output {
+        Self::new(
+            self.vx + rhs.vx,
+            self.vy + rhs.vy,
+            self.vz + rhs.vz,
+            self.mx + rhs.mx,
+            self.my + rhs.my,
+            self.mz + rhs.mz,
+        )
+    }
+}
+impl Sub<Line> for Line {
+    type Output = Self;
+    fn sub(self, rhs: Line) -> Self::Output {
+        self + -rhs
+    }
+}
+impl Neg for Line {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+impl Mul<Float> for Line {
+    type Output = Self;
+    fn mul(self, rhs: Float) -> Self::Output {
+        Self::new(
+            self.vx * rhs,
+            self.vy * rhs,
+            self.vz * rhs,
+            self.mx * rhs,
+            self.my * rhs,
+            self.mz * rhs,
+        )
+    }
+}
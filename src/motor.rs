@@ -1,4 +1,48 @@
-use crate::{point::Point, Float};
+use crate::{
+    direction::Direction, line::Line, plane::Plane, point::Point, rotor::Rotor,
+    translator::Translator, Float,
+};
+
+//The motor product shared by every motor type, so the terms are only written out once. The first
+//form works on the eight components of each motor in field order and gives them back as an
+//array, the second on two structs with the usual field names
+macro_rules! motor_product {
+    (
+        [$svx:expr, $svy:expr, $svz:expr, $svw:expr, $smx:expr, $smy:expr, $smz:expr, $smw:expr],
+        [$ovx:expr, $ovy:expr, $ovz:expr, $ovw:expr, $omx:expr, $omy:expr, $omz:expr, $omw:expr]
+    ) => {
+        [
+            $ovx * $svw + $ovw * $svx + ($ovy * $svz - $ovz * $svy),
+            $ovy * $svw + $ovw * $svy + ($ovz * $svx - $ovx * $svz),
+            $ovz * $svw + $ovw * $svz + ($ovx * $svy - $ovy * $svx),
+            $ovw * $svw - ($ovx * $svx + $ovy * $svy + $ovz * $svz),
+            ($omx * $svw + $ovw * $smx + $ovy * $smz - $omz * $svy + $omy * $svz - $ovz * $smy
+                + $omw * $svx
+                + $ovx * $smw),
+            ($omy * $svw + $ovw * $smy + $ovz * $smx - $omx * $svz + $omz * $svx - $ovx * $smz
+                + $omw * $svy
+                + $ovy * $smw),
+            ($omz * $svw + $ovw * $smz + $ovx * $smy - $omy * $svx + $omx * $svy - $ovy * $smx
+                + $omw * $svz
+                + $ovz * $smw),
+            ($ovw * $smw + $svw * $omw
+                - ($ovx * $smx
+                    + $omx * $svx
+                    + $ovy * $smy
+                    + $omy * $svy
+                    + $ovz * $smz
+                    + $omz * $svz)),
+        ]
+    };
+    ($new:path, $s:expr, $o:expr) => {{
+        let [vx, vy, vz, vw, mx, my, mz, mw] = motor_product!(
+            [$s.vx, $s.vy, $s.vz, $s.vw, $s.mx, $s.my, $s.mz, $s.mw],
+            [$o.vx, $o.vy, $o.vz, $o.vw, $o.mx, $o.my, $o.mz, $o.mw]
+        );
+        $new(vx, vy, vz, vw, mx, my, mz, mw)
+    }};
+}
+pub(crate) use motor_product;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -16,6 +60,7 @@ pub struct Motor {
 }
 impl Motor {
     pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0);
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         vx: Float,
         vy: Float,
@@ -148,58 +193,163 @@ impl Motor {
     }
     #[inline]
     pub fn combine(&self, other: Motor) -> Self {
+        motor_product!(Self::new, self, other)
+    }
+    #[inline]
+    pub fn combine_rotor(&self, other: Rotor) -> Self {
         Self::new(
             other.vx * self.vw + other.vw * self.vx + (other.vy * self.vz - other.vz * self.vy),
             other.vy * self.vw + other.vw * self.vy + (other.vz * self.vx - other.vx * self.vz),
             other.vz * self.vw + other.vw * self.vz + (other.vx * self.vy - other.vy * self.vx),
             other.vw * self.vw - (other.vx * self.vx + other.vy * self.vy + other.vz * self.vz),
-            other.mx * self.vw + other.vw * self.mx + other.vy * self.mz - other.mz * self.vy
-                + other.my * self.vz
-                - other.vz * self.my,
-            other.my * self.vw + other.vw * self.my + other.vz * self.mx - other.mx * self.vz
-                + other.mz * self.vx
-                - other.vx * self.mz,
-            other.mz * self.vw + other.vw * self.mz + other.vx * self.my - other.my * self.vx
-                + other.mx * self.vy
-                - other.vy * self.mx,
-            other.vw * self.mw + self.vw * other.mw
-                - (other.vx * self.mx
-                    + other.mx * self.vx
-                    + other.vy * self.my
-                    + other.my * self.vy
-                    + other.vz * self.mz
-                    + other.mz * self.vz),
+            other.vw * self.mx + other.vy * self.mz - other.vz * self.my + other.vx * self.mw,
+            other.vw * self.my + other.vz * self.mx - other.vx * self.mz + other.vy * self.mw,
+            other.vw * self.mz + other.vx * self.my - other.vy * self.mx + other.vz * self.mw,
+            other.vw * self.mw - (other.vx * self.mx + other.vy * self.my + other.vz * self.mz),
+        )
+    }
+    #[inline]
+    pub fn combine_translator(&self, other: Translator) -> Self {
+        Self::new(
+            self.vx,
+            self.vy,
+            self.vz,
+            self.vw,
+            other.mx * self.vw + self.mx - other.mz * self.vy + other.my * self.vz,
+            other.my * self.vw + self.my - other.mx * self.vz + other.mz * self.vx,
+            other.mz * self.vw + self.mz - other.my * self.vx + other.mx * self.vy,
+            self.mw - (other.mx * self.vx + other.my * self.vy + other.mz * self.vz),
+        )
+    }
+    #[inline]
+    pub fn weight_norm_squared(&self) -> Float {
+        self.vx * self.vx + self.vy * self.vy + self.vz * self.vz + self.vw * self.vw
+    }
+    #[inline]
+    pub fn weight_norm(&self) -> Float {
+        self.weight_norm_squared().sqrt()
+    }
+    //Scales to a unit weight norm and removes any violation of the geometric constraint
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let inv_norm = 1.0 / self.weight_norm();
+        let vx = self.vx * inv_norm;
+        let vy = self.vy * inv_norm;
+        let vz = self.vz * inv_norm;
+        let vw = self.vw * inv_norm;
+        let mx = self.mx * inv_norm;
+        let my = self.my * inv_norm;
+        let mz = self.mz * inv_norm;
+        let mw = self.mw * inv_norm;
+
+        let constraint = vx * mx + vy * my + vz * mz + vw * mw;
+
+        Self::new(
+            vx,
+            vy,
+            vz,
+            vw,
+            mx - constraint * vx,
+            my - constraint * vy,
+            mz - constraint * vz,
+            mw - constraint * vw,
         )
     }
+
     #[inline]
     pub fn transform(&self, p: Point) -> Point {
+        let s = 2.0 / self.weight_norm_squared();
+
         let ax = self.vy * p.z - self.vz * p.y + p.w * self.mx;
         let ay = self.vz * p.x - self.vx * p.z + p.w * self.my;
         let az = self.vx * p.y - self.vy * p.x + p.w * self.mz;
 
         Point::new(
-            p.x + 2.0 * (self.vw * ax + (self.vy * az - self.vz * ay) - self.mw * p.w * self.vx),
-            p.y + 2.0 * (self.vw * ay + (self.vz * ax - self.vx * az) - self.mw * p.w * self.vy),
-            p.z + 2.0 * (self.vw * az + (self.vx * ay - self.vy * ax) - self.mw * p.w * self.vz),
+            p.x + s * (self.vw * ax + (self.vy * az - self.vz * ay) - self.mw * p.w * self.vx),
+            p.y + s * (self.vw * ay + (self.vz * ax - self.vx * az) - self.mw * p.w * self.vy),
+            p.z + s * (self.vw * az + (self.vx * ay - self.vy * ax) - self.mw * p.w * self.vz),
             p.w,
         )
     }
 
+    //Same as transforming an ideal point, but skips the translation terms
     #[inline]
-    pub fn inverse(&self) -> Self {
+    pub fn transform_direction(&self, d: Direction) -> Direction {
+        let [x, y, z] = self.rotate(2.0 / self.weight_norm_squared(), d.x, d.y, d.z);
+        Direction::new(x, y, z)
+    }
+    //The normal rotates like a direction, then the translation moves the plane along it
+    #[inline]
+    pub fn transform_plane(&self, p: Plane) -> Plane {
+        let s = 2.0 / self.weight_norm_squared();
+        let [x, y, z] = self.rotate(s, p.x, p.y, p.z);
+        let [tx, ty, tz] = self.translation(s);
+        Plane::new(x, y, z, p.w - (x * tx + y * ty + z * tz))
+    }
+    //Both parts rotate, then the translation adds its cross product with the direction to the
+    //moment
+    #[inline]
+    pub fn transform_line(&self, l: Line) -> Line {
+        let s = 2.0 / self.weight_norm_squared();
+        let [vx, vy, vz] = self.rotate(s, l.vx, l.vy, l.vz);
+        let [mx, my, mz] = self.rotate(s, l.mx, l.my, l.mz);
+        let [tx, ty, tz] = self.translation(s);
+        Line::new(
+            vx,
+            vy,
+            vz,
+            mx + ty * vz - tz * vy,
+            my + tz * vx - tx * vz,
+            mz + tx * vy - ty * vx,
+        )
+    }
+
+    //Rotation part of the sandwich product, with s being 2 over the squared weight norm
+    #[inline(always)]
+    fn rotate(&self, s: Float, x: Float, y: Float, z: Float) -> [Float; 3] {
+        let ax = self.vy * z - self.vz * y;
+        let ay = self.vz * x - self.vx * z;
+        let az = self.vx * y - self.vy * x;
+        [
+            x + s * (self.vw * ax + (self.vy * az - self.vz * ay)),
+            y + s * (self.vw * ay + (self.vz * ax - self.vx * az)),
+            z + s * (self.vw * az + (self.vx * ay - self.vy * ax)),
+        ]
+    }
+    #[inline(always)]
+    fn translation(&self, s: Float) -> [Float; 3] {
+        [
+            s * (self.vw * self.mx + self.vy * self.mz - self.vz * self.my - self.mw * self.vx),
+            s * (self.vw * self.my + self.vz * self.mx - self.vx * self.mz - self.mw * self.vy),
+            s * (self.vw * self.mz + self.vx * self.my - self.vy * self.mx - self.mw * self.vz),
+        ]
+    }
+
+    #[inline]
+    pub fn reverse(&self) -> Self {
         Self::new(
             -self.vx, -self.vy, -self.vz, self.vw, -self.mx, -self.my, -self.mz, self.mw,
         )
     }
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let s = 1.0 / self.weight_norm_squared();
+        Self::new(
+            -self.vx * s,
+            -self.vy * s,
+            -self.vz * s,
+            self.vw * s,
+            -self.mx * s,
+            -self.my * s,
+            -self.mz * s,
+            self.mw * s,
+        )
+    }
 
     #[inline]
     pub fn translation_euler(&self) -> Point {
-        Point::new(
-            2.0 * (self.vw * self.mx + self.vy * self.mz - self.vz * self.my - self.mw * self.vx),
-            2.0 * (self.vw * self.my + self.vz * self.mx - self.vx * self.mz - self.mw * self.vy),
-            2.0 * (self.vw * self.mz + self.vx * self.my - self.vy * self.mx - self.mw * self.vz),
-            1.0,
-        )
+        let [x, y, z] = self.translation(2.0 / self.weight_norm_squared());
+        Point::new(x, y, z, 1.0)
     }
 
     //Returns (translation, rotation)
@@ -211,14 +361,15 @@ impl Motor {
     }
     #[inline]
     pub fn factor_translation(&self) -> Motor {
+        let s = 1.0 / self.weight_norm_squared();
         Motor::new(
             0.0,
             0.0,
             0.0,
             1.0,
-            self.vw * self.mx + self.vy * self.mz - self.vz * self.my - self.mw * self.vx,
-            self.vw * self.my + self.vz * self.mx - self.vx * self.mz - self.mw * self.vy,
-            self.vw * self.mz + self.vx * self.my - self.vy * self.mx - self.mw * self.vz,
+            s * (self.vw * self.mx + self.vy * self.mz - self.vz * self.my - self.mw * self.vx),
+            s * (self.vw * self.my + self.vz * self.mx - self.vx * self.mz - self.mw * self.vy),
+            s * (self.vw * self.mz + self.vx * self.my - self.vy * self.mx - self.mw * self.vz),
             0.0,
         )
     }
@@ -226,4 +377,106 @@ impl Motor {
     pub fn factor_rotation(&self) -> Motor {
         self.combine(self.factor_translation().inverse())
     }
+    //Splits the rotation into (swing, twist), where the twist turns around the axis and is applied
+    //first, and the swing turns around an axis perpendicular to it. Rotations by half a turn
+    //around a perpendicular axis have no twist
+    pub fn swing_twist(&self, axis: Direction) -> (Motor, Motor) {
+        let r = self.factor_rotation().normalized();
+        let a = axis.normalized();
+        let along = r.vx * a.x + r.vy * a.y + r.vz * a.z;
+        let norm = (along * along + r.vw * r.vw).sqrt();
+        let twist = if norm > Float::EPSILON {
+            Motor::new(
+                a.x * along / norm,
+                a.y * along / norm,
+                a.z * along / norm,
+                r.vw / norm,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+            )
+        } else {
+            Motor::IDENTITY
+        };
+        let swing = twist.inverse().combine(r);
+        (swing, twist)
+    }
+
+    //The twist is a screw velocity stored as a line: the direction part is the angular velocity
+    //and the moment part the linear velocity. A rotation by an angle around a normalized line is
+    //exp(line * angle), a translation is exp of a line with only a moment
+    pub fn exp(twist: Line) -> Self {
+        let (wx, wy, wz) = (twist.vx, twist.vy, twist.vz);
+        let (ux, uy, uz) = (twist.mx, twist.my, twist.mz);
+        let theta_sq = wx * wx + wy * wy + wz * wz;
+        let half = theta_sq.sqrt() * 0.5;
+        let pitch = wx * ux + wy * uy + wz * uz;
+
+        let (sinc, cos, k) = half_angle_terms(half, theta_sq);
+
+        Self::new(
+            0.5 * sinc * wx,
+            0.5 * sinc * wy,
+            0.5 * sinc * wz,
+            cos,
+            0.5 * sinc * ux + pitch * k * wx,
+            0.5 * sinc * uy + pitch * k * wy,
+            0.5 * sinc * uz + pitch * k * wz,
+            -0.25 * pitch * sinc,
+        )
+    }
+    //Inverse of exp, taking the shorter of the two equivalent paths
+    pub fn log(&self) -> Line {
+        let m = self.normalized();
+        let m = if m.vw < 0.0 {
+            Self::new(-m.vx, -m.vy, -m.vz, -m.vw, -m.mx, -m.my, -m.mz, -m.mw)
+        } else {
+            m
+        };
+
+        let sin = (m.vx * m.vx + m.vy * m.vy + m.vz * m.vz).sqrt();
+        let half = sin.atan2(m.vw);
+        let theta_sq = 4.0 * half * half;
+        let (sinc, _, k) = half_angle_terms(half, theta_sq);
+
+        let wx = 2.0 * m.vx / sinc;
+        let wy = 2.0 * m.vy / sinc;
+        let wz = 2.0 * m.vz / sinc;
+        let pitch = -4.0 * m.mw / sinc;
+
+        Line::new(
+            wx,
+            wy,
+            wz,
+            2.0 * (m.mx - pitch * k * wx) / sinc,
+            2.0 * (m.my - pitch * k * wy) / sinc,
+            2.0 * (m.mz - pitch * k * wz) / sinc,
+        )
+    }
+    //Screw interpolation at constant linear and angular speed, reaching self at 0 and other at 1.
+    //Values of t outside of 0 to 1 continue the same screw motion
+    pub fn interpolate(&self, other: Motor, t: Float) -> Self {
+        let delta = self.inverse().combine(other).log();
+        self.combine(Self::exp(delta * t))
+    }
+}
+
+//Returns (sin(half) / half, cos(half), (cos(half) - sin(half) / half) / (2 * theta^2)). The last
+//term cancels catastrophically for small angles, so it switches to its series expansion there
+#[inline]
+fn half_angle_terms(half: Float, theta_sq: Float) -> (Float, Float, Float) {
+    let sinc = if half < Float::EPSILON {
+        1.0
+    } else {
+        half.sin() / half
+    };
+    let cos = half.cos();
+    let half_sq = half * half;
+    let k = if half < 0.05 {
+        -1.0 / 24.0 + half_sq / 240.0 - half_sq * half_sq / 6720.0
+    } else {
+        (cos - sinc) / (2.0 * theta_sq)
+    };
+    (sinc, cos, k)
 }
@@ -1,6 +1,6 @@
-use crate::Float;
+use crate::{direction::Direction, line::Line, point::Point, Float};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[repr(C)]
@@ -14,4 +14,48 @@ impl Plane {
     pub const fn new(x: Float, y: Float, z: Float, w: Float) -> Self {
         Self { x, y, z, w }
     }
+
+    pub fn into_buffer(&self) -> [Float; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+    #[allow(clippy::unnecessary_cast)]
+    pub fn into_buffer_32(&self) -> [f32; 4] {
+        [self.x as f32, self.y as f32, self.z as f32, self.w as f32]
+    }
+
+    pub fn normal(&self) -> Direction {
+        Direction::new(self.x, self.y, self.z)
+    }
+
+    pub fn weight_norm(&self) -> Float {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+    pub fn normalized(&self) -> Self {
+        let inv_norm = 1.0 / self.weight_norm();
+        Self::new(
+            self.x * inv_norm,
+            self.y * inv_norm,
+            self.z * inv_norm,
+            self.w * inv_norm,
+        )
+    }
+
+    //The line where the planes intersect, ideal if they are parallel
+    pub fn meet(&self, rhs: Plane) -> Line {
+        Line::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+            self.w * rhs.x - rhs.w * self.x,
+            self.w * rhs.y - rhs.w * self.y,
+            self.w * rhs.z - rhs.w * self.z,
+        )
+    }
+
+    //Positive on the side the normal points to. Ideal points are infinitely far away, so p must
+    //have a nonzero weight
+    pub fn signed_distance(&self, p: Point) -> Float {
+        debug_assert_ne!(p.w, 0.0);
+        (self.x * p.x + self.y * p.y + self.z * p.z + self.w * p.w) / (self.weight_norm() * p.w)
+    }
 }
@@ -1,6 +1,6 @@
 use std::ops::{Add, Sub};
 
-use crate::{line::Line, plane::Plane, Float};
+use crate::{direction::Direction, line::Line, plane::Plane, Float};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -82,10 +82,12 @@ impl Point {
     pub fn into_buffer(&self) -> [Float; 4] {
         [self.x, self.y, self.z, self.w]
     }
+    #[allow(clippy::unnecessary_cast)]
     pub fn into_buffer_32(&self) -> [f32; 4] {
         [self.x as f32, self.y as f32, self.z as f32, self.w as f32]
     }
 
+    #[allow(clippy::unnecessary_cast)]
     pub fn copy_to_buffer_32(&self, buf: &mut [f32]) {
         buf[0] = self.x as f32;
         buf[1] = self.y as f32;
@@ -134,6 +136,12 @@ impl Point {
         )
     }
 
+    //Drops the weight, so a finite point becomes its weighted position vector. Scale it first to
+    //get the plain position
+    pub fn to_direction_unchecked(&self) -> Direction {
+        Direction::new(self.x, self.y, self.z)
+    }
+
     pub fn round(&self) -> Self {
         Self::new(self.x.round(), self.y.round(), self.z.round(), self.w)
     }
@@ -163,6 +171,7 @@ impl Point {
         self.magnitude_squared().sqrt()
     }
 
+    #[allow(clippy::eq_op)]
     pub fn scaled(&self) -> Self {
         debug_assert_ne!(self.w, 0.0);
         Self {
@@ -0,0 +1,291 @@
+use crate::{line::Line, plane::Plane, point::Point};
+
+//Adaptive precision predicates. Each one is first evaluated in f64 with a forward error bound,
+//and only falls back to exact expansion arithmetic when the result is too close to zero to trust.
+//Signs are exact barring overflow or underflow. Points are taken as is, so a negative weight
+//flips the sign of anything it takes part in
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sign {
+    Negative,
+    Zero,
+    Positive,
+}
+impl Sign {
+    fn of(v: f64) -> Self {
+        if v > 0.0 {
+            Sign::Positive
+        } else if v < 0.0 {
+            Sign::Negative
+        } else {
+            Sign::Zero
+        }
+    }
+    //The components of an expansion increase in magnitude, so the last one decides the sign
+    fn of_expansion(e: &[f64]) -> Self {
+        e.last().map_or(Sign::Zero, |v| Sign::of(*v))
+    }
+}
+
+//Which side of the plane through a, b and c the point d is on. Positive when a, b, c wind
+//counterclockwise as seen from d, matching the sign of a.join(b).join(c) evaluated at d
+pub fn orient3d(a: Point, b: Point, c: Point, d: Point) -> Sign {
+    let (ab, ab_perm) = minors(a, b);
+    let (cd, cd_perm) = minors(c, d);
+
+    let det = plucker(&ab, &cd);
+    let perm = plucker(&ab_perm, &cd_perm);
+    if det.abs() > 16.0 * f64::EPSILON * perm {
+        return Sign::of(det);
+    }
+
+    let ab = exact_minors(a, b);
+    let cd = exact_minors(c, d);
+    let mut sum = Vec::new();
+    for i in 0..3 {
+        sum = expansion_sum(&sum, &expansion_product(&ab[i], &cd[i + 3]));
+        sum = expansion_sum(&sum, &expansion_product(&ab[i + 3], &cd[i]));
+    }
+    Sign::of_expansion(&sum)
+}
+
+pub fn coplanar(a: Point, b: Point, c: Point, d: Point) -> bool {
+    orient3d(a, b, c, d) == Sign::Zero
+}
+
+//True when the three points lie on one line, including when any two of them coincide
+pub fn collinear(a: Point, b: Point, c: Point) -> bool {
+    let (ab, ab_perm) = minors(a, b);
+    let c = to_f64(c);
+
+    //Components of a.join(b).join(c), which all vanish exactly when the points are collinear
+    let terms = |l: &[f64; 6], c: [f64; 4], abs: bool| {
+        let c = if abs { c.map(f64::abs) } else { c };
+        let s = if abs { 1.0 } else { -1.0 };
+        [
+            l[1] * c[2] + s * l[2] * c[1] + l[3] * c[3],
+            l[2] * c[0] + s * l[0] * c[2] + l[4] * c[3],
+            l[0] * c[1] + s * l[1] * c[0] + l[5] * c[3],
+            l[3] * c[0] + l[4] * c[1] + l[5] * c[2],
+        ]
+    };
+    let values = terms(&ab, c, false);
+    let bounds = terms(&ab_perm, c, true);
+    if values
+        .iter()
+        .zip(bounds)
+        .any(|(v, b)| v.abs() > 8.0 * f64::EPSILON * b)
+    {
+        return false;
+    }
+
+    let ab = exact_minors(a, b);
+    let component = |i: usize, ci: usize, j: usize, cj: usize, k: usize, ck: usize, neg: bool| {
+        let first = scale_expansion(&ab[i], c[ci]);
+        let second = scale_expansion(&ab[j], if neg { -c[cj] } else { c[cj] });
+        let third = scale_expansion(&ab[k], c[ck]);
+        expansion_sum(&expansion_sum(&first, &second), &third)
+    };
+    [
+        component(1, 2, 2, 1, 3, 3, true),
+        component(2, 0, 0, 2, 4, 3, true),
+        component(0, 1, 1, 0, 5, 3, true),
+        component(3, 0, 4, 1, 5, 2, false),
+    ]
+    .iter()
+    .all(|e| Sign::of_expansion(e) == Sign::Zero)
+}
+
+impl Plane {
+    //Positive on the side the normal points to
+    #[allow(clippy::unnecessary_cast)]
+    pub fn side_of(&self, p: Point) -> Sign {
+        let [x, y, z, w] = to_f64(p);
+        dot_sign(&[
+            (self.x as f64, x),
+            (self.y as f64, y),
+            (self.z as f64, z),
+            (self.w as f64, w),
+        ])
+    }
+}
+
+impl Line {
+    //Zero when the lines meet or are parallel. Otherwise the sign tells which way one line passes
+    //around the other, and flips if either line is reversed
+    pub fn crossing_sign(&self, other: Line) -> Sign {
+        let a = line_to_f64(self);
+        let b = line_to_f64(&other);
+        dot_sign(&[
+            (a[0], b[3]),
+            (a[1], b[4]),
+            (a[2], b[5]),
+            (a[3], b[0]),
+            (a[4], b[1]),
+            (a[5], b[2]),
+        ])
+    }
+}
+
+#[allow(clippy::unnecessary_cast)]
+fn to_f64(p: Point) -> [f64; 4] {
+    [p.x as f64, p.y as f64, p.z as f64, p.w as f64]
+}
+#[allow(clippy::unnecessary_cast)]
+fn line_to_f64(l: &Line) -> [f64; 6] {
+    [
+        l.vx as f64,
+        l.vy as f64,
+        l.vz as f64,
+        l.mx as f64,
+        l.my as f64,
+        l.mz as f64,
+    ]
+}
+
+//Sign of a sum of products
+pub(crate) fn dot_sign(terms: &[(f64, f64)]) -> Sign {
+    let mut sum = 0.0;
+    let mut perm = 0.0;
+    for (a, b) in terms {
+        sum += a * b;
+        perm += (a * b).abs();
+    }
+    if sum.abs() > (terms.len() as f64 + 1.0) * f64::EPSILON * perm {
+        return Sign::of(sum);
+    }
+
+    let mut exact = Vec::new();
+    for (a, b) in terms {
+        let (p, e) = two_product(*a, *b);
+        exact = expansion_sum(&exact, &[e, p]);
+    }
+    Sign::of_expansion(&exact)
+}
+
+//Sign of a 3x3 determinant
+pub(crate) fn det3_sign(m: [[f64; 3]; 3]) -> Sign {
+    const TERMS: [(usize, usize, usize, f64); 6] = [
+        (0, 1, 2, 1.0),
+        (1, 2, 0, 1.0),
+        (2, 0, 1, 1.0),
+        (0, 2, 1, -1.0),
+        (1, 0, 2, -1.0),
+        (2, 1, 0, -1.0),
+    ];
+
+    let mut det = 0.0;
+    let mut perm = 0.0;
+    for (i, j, k, s) in TERMS {
+        let t = m[0][i] * m[1][j] * m[2][k];
+        det += s * t;
+        perm += t.abs();
+    }
+    if det.abs() > 8.0 * f64::EPSILON * perm {
+        return Sign::of(det);
+    }
+
+    let mut exact = Vec::new();
+    for (i, j, k, s) in TERMS {
+        let (p, e) = two_product(s * m[0][i], m[1][j]);
+        exact = expansion_sum(&exact, &scale_expansion(&[e, p], m[2][k]));
+    }
+    Sign::of_expansion(&exact)
+}
+
+//The 2x2 minors of two points, laid out like the line joining them, along with the same minors
+//taken with absolute values for error bounds
+fn minors(a: Point, b: Point) -> ([f64; 6], [f64; 6]) {
+    let a = to_f64(a);
+    let b = to_f64(b);
+    let pairs = minor_pairs();
+    let mut m = [0.0; 6];
+    let mut perm = [0.0; 6];
+    for (k, (i, j)) in pairs.iter().enumerate() {
+        m[k] = a[*i] * b[*j] - a[*j] * b[*i];
+        perm[k] = (a[*i] * b[*j]).abs() + (a[*j] * b[*i]).abs();
+    }
+    (m, perm)
+}
+fn exact_minors(a: Point, b: Point) -> [Vec<f64>; 6] {
+    let a = to_f64(a);
+    let b = to_f64(b);
+    minor_pairs().map(|(i, j)| {
+        let (p, pe) = two_product(a[i], b[j]);
+        let (q, qe) = two_product(-a[j], b[i]);
+        expansion_sum(&[pe, p], &[qe, q])
+    })
+}
+//Column pairs matching Point::join
+const fn minor_pairs() -> [(usize, usize); 6] {
+    [(3, 0), (3, 1), (3, 2), (1, 2), (2, 0), (0, 1)]
+}
+fn plucker(a: &[f64; 6], b: &[f64; 6]) -> f64 {
+    a[0] * b[3] + a[1] * b[4] + a[2] * b[5] + a[3] * b[0] + a[4] * b[1] + a[5] * b[2]
+}
+
+//Error free transformations and expansion arithmetic, after Shewchuk. Expansions are stored
+//with increasing magnitude and without zero components
+
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let bv = x - a;
+    let av = x - bv;
+    (x, (a - av) + (b - bv))
+}
+#[inline]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+fn grow_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut r = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for v in e {
+        let (sum, err) = two_sum(q, *v);
+        if err != 0.0 {
+            r.push(err);
+        }
+        q = sum;
+    }
+    if q != 0.0 {
+        r.push(q);
+    }
+    r
+}
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |acc, v| grow_expansion(&acc, *v))
+}
+fn scale_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut r = Vec::with_capacity(e.len() * 2);
+    let Some((first, rest)) = e.split_first() else {
+        return r;
+    };
+    let (mut q, err) = two_product(*first, b);
+    if err != 0.0 {
+        r.push(err);
+    }
+    for v in rest {
+        let (product, product_err) = two_product(*v, b);
+        let (sum, err) = two_sum(q, product_err);
+        if err != 0.0 {
+            r.push(err);
+        }
+        let (sum, err) = two_sum(product, sum);
+        if err != 0.0 {
+            r.push(err);
+        }
+        q = sum;
+    }
+    if q != 0.0 {
+        r.push(q);
+    }
+    r
+}
+fn expansion_product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(Vec::new(), |acc, v| {
+        expansion_sum(&acc, &scale_expansion(e, *v))
+    })
+}
@@ -1,4 +1,12 @@
+pub use crate::camera::Camera;
+pub use crate::collineation::Collineation;
+pub use crate::direction::Direction;
+pub use crate::frustum::Frustum;
 pub use crate::line::Line;
 pub use crate::motor::Motor;
 pub use crate::plane::Plane;
 pub use crate::point::Point;
+pub use crate::rotor::Rotor;
+pub use crate::simd::{LineX4, LineX8, MotorX4, MotorX8, PointX4, PointX8};
+pub use crate::translator::Translator;
+pub use crate::unit::{UnitLine, UnitMotor, UnitPlane};
@@ -0,0 +1,126 @@
+use crate::{
+    line::Line,
+    plane::Plane,
+    point::Point,
+    predicates::{det3_sign, dot_sign, Sign},
+    Float,
+};
+
+//Projective invariants and incidence tests. Parallel elements meet at infinity, so parallel lines
+//count as concurrent and parallel planes as coaxial. Collinear points and coplanar points are
+//covered by predicates::collinear and predicates::coplanar
+
+//(a, b; c, d) for four collinear points. Weights cancel out, and the value is unchanged by any
+//motor or other collineation
+pub fn cross_ratio(a: Point, b: Point, c: Point, d: Point) -> Float {
+    let lines = [a.join(c), b.join(d), a.join(d), b.join(c)].map(|l| line_to_array(&l));
+    bracket_ratio(lines)
+}
+//The dual of cross_ratio, for four planes through a common line
+pub fn plane_cross_ratio(a: Plane, b: Plane, c: Plane, d: Plane) -> Float {
+    let lines = [a.meet(c), b.meet(d), a.meet(d), b.meet(c)].map(|l| line_to_array(&l));
+    bracket_ratio(lines)
+}
+
+//The point d on the line through a and b with (a, b; c, d) = -1. The harmonic conjugate of the
+//midpoint is the ideal point of the line
+pub fn harmonic_conjugate(a: Point, b: Point, c: Point) -> Point {
+    let [cb, ac] = bracket([c.join(b), a.join(c)].map(|l| line_to_array(&l)));
+    Point::new(
+        cb * a.x - ac * b.x,
+        cb * a.y - ac * b.y,
+        cb * a.z - ac * b.z,
+        cb * a.w - ac * b.w,
+    )
+}
+
+//Also true for parallel lines, and for lines that coincide
+pub fn coplanar_lines(a: Line, b: Line) -> bool {
+    a.crossing_sign(b) == Sign::Zero
+}
+
+//True when all three lines pass through one point, which may be ideal
+pub fn concurrent_lines(a: Line, b: Line, c: Line) -> bool {
+    if !(coplanar_lines(a, b) && coplanar_lines(b, c) && coplanar_lines(a, c)) {
+        return false;
+    }
+    //Lines that meet pairwise either all pass through a point or all lie in a plane. Three lines
+    //of a flat pencil are linearly dependent, and are both
+    let lines = [a, b, c].map(|l| line_to_array(&l).map(f64::from));
+    if rank_below_3(&lines) {
+        return true;
+    }
+    //Otherwise their directions tell the two cases apart. Lines through a finite point have
+    //independent directions, and lines through an ideal point share one. Lines in a plane have
+    //directions spanning only the plane, or none at all for the ideal plane
+    let v = lines.map(|l| [l[0], l[1], l[2]]);
+    if det3_sign(v) != Sign::Zero {
+        return true;
+    }
+    let parallel = |p: [f64; 3], q: [f64; 3]| {
+        [
+            [(p[1], q[2]), (-p[2], q[1])],
+            [(p[2], q[0]), (-p[0], q[2])],
+            [(p[0], q[1]), (-p[1], q[0])],
+        ]
+        .iter()
+        .all(|t| dot_sign(t) == Sign::Zero)
+    };
+    v.iter().any(|d| d.iter().any(|x| *x != 0.0))
+        && parallel(v[0], v[1])
+        && parallel(v[1], v[2])
+        && parallel(v[0], v[2])
+}
+
+//True when all three planes contain one line, which may be ideal
+pub fn coaxial_planes(a: Plane, b: Plane, c: Plane) -> bool {
+    let planes = [a, b, c].map(|p| p.into_buffer().map(f64::from));
+    (0..4).all(|skip| {
+        let m = planes.map(|p| {
+            let mut r = [0.0; 3];
+            for (dst, src) in r.iter_mut().zip((0..4).filter(|i| *i != skip)) {
+                *dst = p[src];
+            }
+            r
+        });
+        det3_sign(m) == Sign::Zero
+    })
+}
+
+fn line_to_array(l: &Line) -> [Float; 6] {
+    [l.vx, l.vy, l.vz, l.mx, l.my, l.mz]
+}
+
+//Lines spanned by the same pair of elements differ only by scale, so any one component can stand
+//in for the whole line. The largest one is picked to keep the ratios well conditioned
+fn bracket<const N: usize>(lines: [[Float; 6]; N]) -> [Float; N] {
+    let mut best = 0;
+    let mut best_size = 0.0;
+    for k in 0..6 {
+        let size: Float = lines.iter().map(|l| l[k].abs()).sum();
+        if size > best_size {
+            best = k;
+            best_size = size;
+        }
+    }
+    lines.map(|l| l[best])
+}
+fn bracket_ratio(lines: [[Float; 6]; 4]) -> Float {
+    let [ac, bd, ad, bc] = bracket(lines);
+    (ac * bd) / (ad * bc)
+}
+
+//Whether the 6x3 matrix of line coordinates has rank below 3
+fn rank_below_3(lines: &[[f64; 6]; 3]) -> bool {
+    for i in 0..6 {
+        for j in i + 1..6 {
+            for k in j + 1..6 {
+                let m = lines.map(|l| [l[i], l[j], l[k]]);
+                if det3_sign(m) != Sign::Zero {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
@@ -0,0 +1,126 @@
+use crate::{direction::Direction, motor::Motor, point::Point, translator::Translator, Float};
+
+//A motor with no translation part, i.e. a pure rotation around an axis through the origin
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Rotor {
+    pub vx: Float,
+    pub vy: Float,
+    pub vz: Float,
+    pub vw: Float,
+}
+impl Default for Rotor {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+impl Rotor {
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    pub const fn new(vx: Float, vy: Float, vz: Float, vw: Float) -> Self {
+        Self { vx, vy, vz, vw }
+    }
+    #[inline]
+    pub fn from_rotation_around_axis(
+        axis_x: Float,
+        axis_y: Float,
+        axis_z: Float,
+        angle: Float,
+    ) -> Self {
+        debug_assert!(
+            (axis_x * axis_x + axis_y * axis_y + axis_z * axis_z - 1.0).abs() < 0.01,
+            "Axis not normalized"
+        );
+        let angle = angle * 0.5;
+        let sin_angle = angle.sin();
+        let cos_angle = angle.cos();
+
+        Self::new(
+            axis_x * sin_angle,
+            axis_y * sin_angle,
+            axis_z * sin_angle,
+            cos_angle,
+        )
+    }
+    //Order is z, x, then y
+    #[inline]
+    pub fn from_euler_angles(x: Float, y: Float, z: Float) -> Self {
+        let m = Motor::from_euler_angles(x, y, z);
+        Self::new(m.vx, m.vy, m.vz, m.vw)
+    }
+
+    #[inline]
+    pub fn combine(&self, other: Rotor) -> Self {
+        Self::new(
+            other.vx * self.vw + other.vw * self.vx + (other.vy * self.vz - other.vz * self.vy),
+            other.vy * self.vw + other.vw * self.vy + (other.vz * self.vx - other.vx * self.vz),
+            other.vz * self.vw + other.vw * self.vz + (other.vx * self.vy - other.vy * self.vx),
+            other.vw * self.vw - (other.vx * self.vx + other.vy * self.vy + other.vz * self.vz),
+        )
+    }
+    //Rotate, then translate
+    #[inline]
+    pub fn combine_translator(&self, other: Translator) -> Motor {
+        Motor::new(
+            self.vx,
+            self.vy,
+            self.vz,
+            self.vw,
+            other.mx * self.vw - other.mz * self.vy + other.my * self.vz,
+            other.my * self.vw - other.mx * self.vz + other.mz * self.vx,
+            other.mz * self.vw - other.my * self.vx + other.mx * self.vy,
+            -(other.mx * self.vx + other.my * self.vy + other.mz * self.vz),
+        )
+    }
+    #[inline]
+    pub fn combine_motor(&self, other: Motor) -> Motor {
+        Motor::new(
+            other.vx * self.vw + other.vw * self.vx + (other.vy * self.vz - other.vz * self.vy),
+            other.vy * self.vw + other.vw * self.vy + (other.vz * self.vx - other.vx * self.vz),
+            other.vz * self.vw + other.vw * self.vz + (other.vx * self.vy - other.vy * self.vx),
+            other.vw * self.vw - (other.vx * self.vx + other.vy * self.vy + other.vz * self.vz),
+            other.mx * self.vw - other.mz * self.vy + other.my * self.vz + other.mw * self.vx,
+            other.my * self.vw - other.mx * self.vz + other.mz * self.vx + other.mw * self.vy,
+            other.mz * self.vw - other.my * self.vx + other.mx * self.vy + other.mw * self.vz,
+            other.mw * self.vw - (other.mx * self.vx + other.my * self.vy + other.mz * self.vz),
+        )
+    }
+
+    #[inline]
+    pub fn transform(&self, p: Point) -> Point {
+        let ax = self.vy * p.z - self.vz * p.y;
+        let ay = self.vz * p.x - self.vx * p.z;
+        let az = self.vx * p.y - self.vy * p.x;
+
+        Point::new(
+            p.x + 2.0 * (self.vw * ax + (self.vy * az - self.vz * ay)),
+            p.y + 2.0 * (self.vw * ay + (self.vz * ax - self.vx * az)),
+            p.z + 2.0 * (self.vw * az + (self.vx * ay - self.vy * ax)),
+            p.w,
+        )
+    }
+
+    #[inline]
+    pub fn transform_direction(&self, d: Direction) -> Direction {
+        let ax = self.vy * d.z - self.vz * d.y;
+        let ay = self.vz * d.x - self.vx * d.z;
+        let az = self.vx * d.y - self.vy * d.x;
+
+        Direction::new(
+            d.x + 2.0 * (self.vw * ax + (self.vy * az - self.vz * ay)),
+            d.y + 2.0 * (self.vw * ay + (self.vz * ax - self.vx * az)),
+            d.z + 2.0 * (self.vw * az + (self.vx * ay - self.vy * ax)),
+        )
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self::new(-self.vx, -self.vy, -self.vz, self.vw)
+    }
+}
+impl From<Rotor> for Motor {
+    fn from(value: Rotor) -> Self {
+        Motor::new(value.vx, value.vy, value.vz, value.vw, 0.0, 0.0, 0.0, 0.0)
+    }
+}
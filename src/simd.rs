@@ -0,0 +1,419 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::{
+    line::Line,
+    motor::{motor_product, Motor},
+    point::Point,
+    Float,
+};
+
+//Every operation here mirrors the scalar version term for term, so results are bit-identical.
+//Motor transforms mirror UnitMotor, which skips the division by the weight norm
+
+pub(crate) trait Lanes:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    fn splat(v: Float) -> Self;
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "f64")))]
+mod lanes {
+    use std::arch::x86_64::*;
+    use std::ops::{Add, Mul, Sub};
+
+    use super::Lanes;
+
+    //SSE is part of the x86_64 baseline, so the intrinsics below are always available
+    #[derive(Clone, Copy)]
+    pub(crate) struct F4(__m128);
+    impl F4 {
+        #[inline(always)]
+        pub(crate) fn load(v: &[f32; 4]) -> Self {
+            Self(unsafe { _mm_loadu_ps(v.as_ptr()) })
+        }
+        #[inline(always)]
+        pub(crate) fn store(self, v: &mut [f32; 4]) {
+            unsafe { _mm_storeu_ps(v.as_mut_ptr(), self.0) }
+        }
+    }
+    impl Lanes for F4 {
+        #[inline(always)]
+        fn splat(v: f32) -> Self {
+            Self(unsafe { _mm_set1_ps(v) })
+        }
+    }
+    impl Add for F4 {
+        type Output = Self;
+        #[inline(always)]
+        fn add(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_add_ps(self.0, rhs.0) })
+        }
+    }
+    impl Sub for F4 {
+        type Output = Self;
+        #[inline(always)]
+        fn sub(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_sub_ps(self.0, rhs.0) })
+        }
+    }
+    impl Mul for F4 {
+        type Output = Self;
+        #[inline(always)]
+        fn mul(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_mul_ps(self.0, rhs.0) })
+        }
+    }
+}
+
+//Scalar fallback, left for the compiler to vectorize
+#[cfg(not(all(target_arch = "x86_64", not(feature = "f64"))))]
+mod lanes {
+    use std::ops::{Add, Mul, Sub};
+
+    use super::Lanes;
+    use crate::Float;
+
+    #[derive(Clone, Copy)]
+    pub(crate) struct F4([Float; 4]);
+    impl F4 {
+        #[inline(always)]
+        pub(crate) fn load(v: &[Float; 4]) -> Self {
+            Self(*v)
+        }
+        #[inline(always)]
+        pub(crate) fn store(self, v: &mut [Float; 4]) {
+            *v = self.0;
+        }
+    }
+    impl Lanes for F4 {
+        #[inline(always)]
+        fn splat(v: Float) -> Self {
+            Self([v; 4])
+        }
+    }
+    macro_rules! lanewise {
+        ($tr:ident, $f:ident, $op:tt) => {
+            impl $tr for F4 {
+                type Output = Self;
+                #[inline(always)]
+                fn $f(self, rhs: Self) -> Self {
+                    Self([
+                        self.0[0] $op rhs.0[0],
+                        self.0[1] $op rhs.0[1],
+                        self.0[2] $op rhs.0[2],
+                        self.0[3] $op rhs.0[3],
+                    ])
+                }
+            }
+        };
+    }
+    lanewise!(Add, add, +);
+    lanewise!(Sub, sub, -);
+    lanewise!(Mul, mul, *);
+}
+
+use lanes::F4;
+
+//Eight lanes as two halves of four
+#[derive(Clone, Copy)]
+pub(crate) struct F8(F4, F4);
+impl F8 {
+    #[inline(always)]
+    fn load(v: &[Float; 8]) -> Self {
+        let (lo, hi) = v.split_at(4);
+        Self(
+            F4::load(lo.try_into().unwrap()),
+            F4::load(hi.try_into().unwrap()),
+        )
+    }
+    #[inline(always)]
+    fn store(self, v: &mut [Float; 8]) {
+        let (lo, hi) = v.split_at_mut(4);
+        self.0.store(lo.try_into().unwrap());
+        self.1.store(hi.try_into().unwrap());
+    }
+}
+impl Lanes for F8 {
+    #[inline(always)]
+    fn splat(v: Float) -> Self {
+        Self(F4::splat(v), F4::splat(v))
+    }
+}
+impl Add for F8 {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+impl Sub for F8 {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+impl Mul for F8 {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0 * rhs.0, self.1 * rhs.1)
+    }
+}
+
+#[inline(always)]
+fn join<L: Lanes>([x0, y0, z0, w0]: [L; 4], [x1, y1, z1, w1]: [L; 4]) -> [L; 6] {
+    [
+        w0 * x1 - x0 * w1,
+        w0 * y1 - y0 * w1,
+        w0 * z1 - z0 * w1,
+        y0 * z1 - z0 * y1,
+        z0 * x1 - x0 * z1,
+        x0 * y1 - y0 * x1,
+    ]
+}
+
+#[inline(always)]
+fn dot<L: Lanes>([x0, y0, z0, w0]: [L; 4], [x1, y1, z1, w1]: [L; 4]) -> L {
+    x0 * x1 + y0 * y1 + z0 * z1 + w0 * w1
+}
+
+#[inline(always)]
+fn transform<L: Lanes>([vx, vy, vz, vw, mx, my, mz, mw]: [L; 8], [x, y, z, w]: [L; 4]) -> [L; 4] {
+    let two = L::splat(2.0);
+
+    let ax = vy * z - vz * y + w * mx;
+    let ay = vz * x - vx * z + w * my;
+    let az = vx * y - vy * x + w * mz;
+
+    [
+        x + two * (vw * ax + (vy * az - vz * ay) - mw * w * vx),
+        y + two * (vw * ay + (vz * ax - vx * az) - mw * w * vy),
+        z + two * (vw * az + (vx * ay - vy * ax) - mw * w * vz),
+        w,
+    ]
+}
+
+#[inline(always)]
+fn combine<L: Lanes>(
+    [svx, svy, svz, svw, smx, smy, smz, smw]: [L; 8],
+    [ovx, ovy, ovz, ovw, omx, omy, omz, omw]: [L; 8],
+) -> [L; 8] {
+    motor_product!(
+        [svx, svy, svz, svw, smx, smy, smz, smw],
+        [ovx, ovy, ovz, ovw, omx, omy, omz, omw]
+    )
+}
+
+macro_rules! soa_types {
+    ($lanes:ident, $n:literal, $point:ident, $line:ident, $motor:ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(C)]
+        pub struct $point {
+            pub x: [Float; $n],
+            pub y: [Float; $n],
+            pub z: [Float; $n],
+            pub w: [Float; $n],
+        }
+        impl Default for $point {
+            fn default() -> Self {
+                Self::splat(Point::default())
+            }
+        }
+        impl $point {
+            pub fn splat(p: Point) -> Self {
+                Self {
+                    x: [p.x; $n],
+                    y: [p.y; $n],
+                    z: [p.z; $n],
+                    w: [p.w; $n],
+                }
+            }
+            pub fn from_points(points: [Point; $n]) -> Self {
+                Self {
+                    x: points.map(|p| p.x),
+                    y: points.map(|p| p.y),
+                    z: points.map(|p| p.z),
+                    w: points.map(|p| p.w),
+                }
+            }
+            pub fn to_points(&self) -> [Point; $n] {
+                std::array::from_fn(|i| Point::new(self.x[i], self.y[i], self.z[i], self.w[i]))
+            }
+
+            #[inline(always)]
+            fn load(&self) -> [$lanes; 4] {
+                [
+                    $lanes::load(&self.x),
+                    $lanes::load(&self.y),
+                    $lanes::load(&self.z),
+                    $lanes::load(&self.w),
+                ]
+            }
+            #[inline(always)]
+            fn store([x, y, z, w]: [$lanes; 4]) -> Self {
+                let mut r = Self::splat(Point::default());
+                x.store(&mut r.x);
+                y.store(&mut r.y);
+                z.store(&mut r.z);
+                w.store(&mut r.w);
+                r
+            }
+
+            #[inline]
+            pub fn join(&self, rhs: &$point) -> $line {
+                $line::store(join(self.load(), rhs.load()))
+            }
+            #[inline]
+            pub fn dot(&self, rhs: &$point) -> [Float; $n] {
+                let mut r = [0.0; $n];
+                dot(self.load(), rhs.load()).store(&mut r);
+                r
+            }
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(C)]
+        pub struct $line {
+            pub vx: [Float; $n],
+            pub vy: [Float; $n],
+            pub vz: [Float; $n],
+            pub mx: [Float; $n],
+            pub my: [Float; $n],
+            pub mz: [Float; $n],
+        }
+        impl $line {
+            pub fn splat(l: Line) -> Self {
+                Self {
+                    vx: [l.vx; $n],
+                    vy: [l.vy; $n],
+                    vz: [l.vz; $n],
+                    mx: [l.mx; $n],
+                    my: [l.my; $n],
+                    mz: [l.mz; $n],
+                }
+            }
+            pub fn from_lines(lines: [Line; $n]) -> Self {
+                Self {
+                    vx: lines.map(|l| l.vx),
+                    vy: lines.map(|l| l.vy),
+                    vz: lines.map(|l| l.vz),
+                    mx: lines.map(|l| l.mx),
+                    my: lines.map(|l| l.my),
+                    mz: lines.map(|l| l.mz),
+                }
+            }
+            pub fn to_lines(&self) -> [Line; $n] {
+                std::array::from_fn(|i| {
+                    Line::new(
+                        self.vx[i], self.vy[i], self.vz[i], self.mx[i], self.my[i], self.mz[i],
+                    )
+                })
+            }
+
+            #[inline(always)]
+            fn store([vx, vy, vz, mx, my, mz]: [$lanes; 6]) -> Self {
+                let mut r = Self::splat(Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+                vx.store(&mut r.vx);
+                vy.store(&mut r.vy);
+                vz.store(&mut r.vz);
+                mx.store(&mut r.mx);
+                my.store(&mut r.my);
+                mz.store(&mut r.mz);
+                r
+            }
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(C)]
+        pub struct $motor {
+            pub vx: [Float; $n],
+            pub vy: [Float; $n],
+            pub vz: [Float; $n],
+            pub vw: [Float; $n],
+            pub mx: [Float; $n],
+            pub my: [Float; $n],
+            pub mz: [Float; $n],
+            pub mw: [Float; $n],
+        }
+        impl Default for $motor {
+            fn default() -> Self {
+                Self::splat(Motor::IDENTITY)
+            }
+        }
+        impl $motor {
+            pub fn splat(m: Motor) -> Self {
+                Self {
+                    vx: [m.vx; $n],
+                    vy: [m.vy; $n],
+                    vz: [m.vz; $n],
+                    vw: [m.vw; $n],
+                    mx: [m.mx; $n],
+                    my: [m.my; $n],
+                    mz: [m.mz; $n],
+                    mw: [m.mw; $n],
+                }
+            }
+            pub fn from_motors(motors: [Motor; $n]) -> Self {
+                Self {
+                    vx: motors.map(|m| m.vx),
+                    vy: motors.map(|m| m.vy),
+                    vz: motors.map(|m| m.vz),
+                    vw: motors.map(|m| m.vw),
+                    mx: motors.map(|m| m.mx),
+                    my: motors.map(|m| m.my),
+                    mz: motors.map(|m| m.mz),
+                    mw: motors.map(|m| m.mw),
+                }
+            }
+            pub fn to_motors(&self) -> [Motor; $n] {
+                std::array::from_fn(|i| {
+                    Motor::new(
+                        self.vx[i], self.vy[i], self.vz[i], self.vw[i], self.mx[i], self.my[i],
+                        self.mz[i], self.mw[i],
+                    )
+                })
+            }
+
+            #[inline(always)]
+            fn load(&self) -> [$lanes; 8] {
+                [
+                    $lanes::load(&self.vx),
+                    $lanes::load(&self.vy),
+                    $lanes::load(&self.vz),
+                    $lanes::load(&self.vw),
+                    $lanes::load(&self.mx),
+                    $lanes::load(&self.my),
+                    $lanes::load(&self.mz),
+                    $lanes::load(&self.mw),
+                ]
+            }
+            #[inline(always)]
+            fn store([vx, vy, vz, vw, mx, my, mz, mw]: [$lanes; 8]) -> Self {
+                let mut r = Self::splat(Motor::IDENTITY);
+                vx.store(&mut r.vx);
+                vy.store(&mut r.vy);
+                vz.store(&mut r.vz);
+                vw.store(&mut r.vw);
+                mx.store(&mut r.mx);
+                my.store(&mut r.my);
+                mz.store(&mut r.mz);
+                mw.store(&mut r.mw);
+                r
+            }
+
+            #[inline]
+            pub fn combine(&self, other: &$motor) -> Self {
+                Self::store(combine(self.load(), other.load()))
+            }
+            //Like UnitMotor::transform, the motors are assumed to be normalized
+            #[inline]
+            pub fn transform(&self, p: &$point) -> $point {
+                $point::store(transform(self.load(), p.load()))
+            }
+        }
+    };
+}
+
+soa_types!(F4, 4, PointX4, LineX4, MotorX4);
+soa_types!(F8, 8, PointX8, LineX8, MotorX8);
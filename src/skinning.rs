@@ -0,0 +1,127 @@
+use crate::{
+    blend::blend,
+    direction::Direction,
+    motor::Motor,
+    point::Point,
+    simd::{MotorX4, PointX4},
+    unit::UnitMotor,
+    Float,
+};
+
+//Up to four bones per vertex, as most vertex formats store them. Influences with a weight of
+//zero are skipped, so unused slots can point at any bone
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Influences {
+    pub bones: [u16; 4],
+    pub weights: [Float; 4],
+}
+impl Influences {
+    pub const fn new(bones: [u16; 4], weights: [Float; 4]) -> Self {
+        Self { bones, weights }
+    }
+    pub const fn single(bone: u16) -> Self {
+        Self::new([bone, 0, 0, 0], [1.0, 0.0, 0.0, 0.0])
+    }
+}
+
+//Dual quaternion skinning. Bone motors move the bind pose into the current pose, so they usually
+//combine the inverse bind motor with the bone's current world motor. Vertices with no weight keep
+//the bind pose
+pub fn skin_motor(bones: &[Motor], influences: &Influences) -> Motor {
+    let mut pairs = [(Motor::IDENTITY, 0.0); 4];
+    let mut n = 0;
+    for (bone, weight) in influences.bones.iter().zip(influences.weights) {
+        if weight > 0.0 {
+            pairs[n] = (bones[*bone as usize], weight);
+            n += 1;
+        }
+    }
+    blend(&pairs[..n]).unwrap_or(Motor::IDENTITY)
+}
+
+pub fn skin_motors(bones: &[Motor], influences: &[Influences], dst: &mut [Motor]) {
+    assert_eq!(
+        influences.len(),
+        dst.len(),
+        "Source and destination lengths differ"
+    );
+    for (d, i) in dst.iter_mut().zip(influences) {
+        *d = skin_motor(bones, i);
+    }
+}
+
+//Transforms four vertices at a time, giving the same results as UnitMotor::transform with each
+//vertex's skinned motor, which blending always normalizes
+pub fn skin_points(bones: &[Motor], influences: &[Influences], src: &[Point], dst: &mut [Point]) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "Source and destination lengths differ"
+    );
+    assert_eq!(
+        influences.len(),
+        src.len(),
+        "Every vertex needs its influences"
+    );
+    let mut chunks = dst.chunks_exact_mut(4);
+    for ((d, s), i) in (&mut chunks)
+        .zip(src.chunks_exact(4))
+        .zip(influences.chunks_exact(4))
+    {
+        let motors = MotorX4::from_motors(std::array::from_fn(|j| skin_motor(bones, &i[j])));
+        let points = PointX4::from_points(s.try_into().unwrap());
+        d.copy_from_slice(&motors.transform(&points).to_points());
+    }
+    let done = src.len() - chunks.into_remainder().len();
+    for ((d, s), i) in dst[done..]
+        .iter_mut()
+        .zip(&src[done..])
+        .zip(&influences[done..])
+    {
+        *d = UnitMotor::new_unchecked(skin_motor(bones, i)).transform(*s);
+    }
+}
+
+pub fn skin_directions(
+    bones: &[Motor],
+    influences: &[Influences],
+    src: &[Direction],
+    dst: &mut [Direction],
+) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "Source and destination lengths differ"
+    );
+    assert_eq!(
+        influences.len(),
+        src.len(),
+        "Every vertex needs its influences"
+    );
+    for ((d, s), i) in dst.iter_mut().zip(src).zip(influences) {
+        *d = skin_motor(bones, i).transform_direction(*s);
+    }
+}
+
+//Positions and normals together, blending each vertex's motor once
+pub fn skin_points_and_normals(
+    bones: &[Motor],
+    influences: &[Influences],
+    points: &mut [Point],
+    normals: &mut [Direction],
+) {
+    assert_eq!(points.len(), normals.len(), "Attribute lengths differ");
+    assert_eq!(
+        influences.len(),
+        points.len(),
+        "Every vertex needs its influences"
+    );
+    for ((p, n), i) in points.iter_mut().zip(normals.iter_mut()).zip(influences) {
+        let m = skin_motor(bones, i);
+        *p = m.transform(*p);
+        *n = m.transform_direction(*n);
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{direction::Direction, line::Line, motor::Motor, point::Point, Float};
+
+//Curves are built only from screw interpolation, so a path along a single screw stays on that
+//screw instead of drifting the way separately splined translation and rotation do. Velocities are
+//world frame twists in the units of Motor::log per unit of the curve parameter, so over a short
+//step h a curve moves from m to m.combine(Motor::exp(velocity * h))
+
+//Bezier curve through repeated screw interpolation of the control motors
+pub fn de_casteljau(controls: &[Motor], t: Float) -> Motor {
+    assert!(controls.len() >= 2, "Bezier curves need two control motors");
+    let mut level = controls.to_vec();
+    while level.len() > 1 {
+        for i in 0..level.len() - 1 {
+            level[i] = level[i].interpolate(level[i + 1], t);
+        }
+        level.pop();
+    }
+    level[0]
+}
+//Every level interpolates between motors that are themselves moving, so their rates are carried up
+//through the log and exp of each interpolation
+pub fn de_casteljau_velocity(controls: &[Motor], t: Float) -> Line {
+    assert!(controls.len() >= 2, "Bezier curves need two control motors");
+    let zero = Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    let mut level: Vec<(Motor, Line)> = controls.iter().map(|m| (*m, zero)).collect();
+    while level.len() > 1 {
+        for i in 0..level.len() - 1 {
+            level[i] = interpolate_with_rate(level[i], level[i + 1], t);
+        }
+        level.pop();
+    }
+    level[0].1
+}
+
+//a.interpolate(b, t) along with its rate, given the rates of a and b
+fn interpolate_with_rate(
+    (a, rate_a): (Motor, Line),
+    (b, rate_b): (Motor, Line),
+    t: Float,
+) -> (Motor, Line) {
+    let delta = a.inverse().combine(b);
+    let log = delta.log();
+    let rate_log = log_rate(log, rate_b - delta.transform_line(rate_a));
+    let step = Motor::exp(log * t);
+    let rate = step.transform_line(rate_a) + log + exp_rate(log * t, rate_log) * t;
+    (a.combine(step), rate)
+}
+
+//Rates through exp and log, from the closed forms of their series in the twist's bracket. A
+//twist's angle and pitch act together as a dual number, so each coefficient is evaluated at the
+//angle and extended by its derivative times the pitch
+
+//The rate of Motor::exp(x) when x changes at rate y
+fn exp_rate(x: Line, y: Line) -> Line {
+    let [a, b] = exp_coefficients(x.weight_norm());
+    let xy = bracket(x, y);
+    y - dual_scale(x, a, xy) + dual_scale(x, b, bracket(x, xy))
+}
+//The rate of log(m) when m changes at rate y, where x is log(m)
+fn log_rate(x: Line, y: Line) -> Line {
+    let c = log_coefficient(x.weight_norm());
+    let xy = bracket(x, y);
+    y + xy * 0.5 + dual_scale(x, c, bracket(x, xy))
+}
+
+fn bracket(x: Line, y: Line) -> Line {
+    let (xw, xm) = (x.direction(), Direction::new(x.mx, x.my, x.mz));
+    let (yw, ym) = (y.direction(), Direction::new(y.mx, y.my, y.mz));
+    let w = yw.cross(xw);
+    let m = yw.cross(xm) + ym.cross(xw);
+    Line::new(w.x, w.y, w.z, m.x, m.y, m.z)
+}
+//Scales y by a coefficient and its derivative over the angle, evaluated at the dual angle of x
+fn dual_scale(x: Line, (f, df): (Float, Float), y: Line) -> Line {
+    let pitch = x.vx * x.mx + x.vy * x.my + x.vz * x.mz;
+    y * f + Line::new(0.0, 0.0, 0.0, y.vx, y.vy, y.vz) * (df * pitch)
+}
+
+//(1 - cos) / angle^2 and (angle - sin) / angle^3, each with its derivative over the angle. Series
+//expansions avoid the cancellation for small angles
+fn exp_coefficients(angle: Float) -> [(Float, Float); 2] {
+    let a2 = angle * angle;
+    if angle < 1.0 {
+        [
+            (
+                0.5 - a2 / 24.0 + a2 * a2 / 720.0 - a2 * a2 * a2 / 40320.0,
+                -1.0 / 12.0 + a2 / 180.0 - a2 * a2 / 6720.0 + a2 * a2 * a2 / 453600.0,
+            ),
+            (
+                1.0 / 6.0 - a2 / 120.0 + a2 * a2 / 5040.0 - a2 * a2 * a2 / 362880.0,
+                -1.0 / 60.0 + a2 / 1260.0 - a2 * a2 / 60480.0 + a2 * a2 * a2 / 4989600.0,
+            ),
+        ]
+    } else {
+        let (sin, cos) = angle.sin_cos();
+        [
+            (
+                (1.0 - cos) / a2,
+                (angle * sin - 2.0 * (1.0 - cos)) / (a2 * a2),
+            ),
+            (
+                (angle - sin) / (a2 * angle),
+                (angle * (1.0 - cos) - 3.0 * (angle - sin)) / (a2 * a2 * angle),
+            ),
+        ]
+    }
+}
+//(1 - angle / 2 * cot(angle / 2)) / angle^2 with its derivative over the angle. Logs turn at most
+//half a turn, well clear of the pole at a full turn
+fn log_coefficient(angle: Float) -> (Float, Float) {
+    let a2 = angle * angle;
+    if angle < 1.0 {
+        (
+            1.0 / 12.0 + a2 / 720.0 + a2 * a2 / 30240.0 + a2 * a2 * a2 / 1209600.0,
+            1.0 / 360.0 + a2 / 7560.0 + a2 * a2 / 201600.0,
+        )
+    } else {
+        let half = angle * 0.5;
+        let g = half / half.tan();
+        let dg = 0.5 / half.tan() - 0.5 * half / (half.sin() * half.sin());
+        ((1.0 - g) / a2, (-dg * angle - 2.0 * (1.0 - g)) / (a2 * a2))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SplineKind {
+    //Passes through every motor with C1 continuity, using tangents from the neighbouring motors
+    CatmullRom,
+    //Uniform cubic B-spline in cumulative form. C2 continuous, but only approaches the motors
+    BSpline,
+}
+
+//A uniform spline through a sequence of motors. The parameter runs from 0 to segments(), with
+//each whole number starting a segment
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MotorSpline {
+    pub kind: SplineKind,
+    motors: Vec<Motor>,
+}
+impl MotorSpline {
+    pub fn new(kind: SplineKind, motors: Vec<Motor>) -> Self {
+        let min = match kind {
+            SplineKind::CatmullRom => 2,
+            SplineKind::BSpline => 4,
+        };
+        assert!(motors.len() >= min, "Not enough motors for the spline");
+        Self { kind, motors }
+    }
+
+    pub fn motors(&self) -> &[Motor] {
+        &self.motors
+    }
+    pub fn segments(&self) -> usize {
+        match self.kind {
+            SplineKind::CatmullRom => self.motors.len() - 1,
+            SplineKind::BSpline => self.motors.len() - 3,
+        }
+    }
+
+    pub fn sample(&self, t: Float) -> Motor {
+        let (i, u) = self.segment(t);
+        match self.kind {
+            SplineKind::CatmullRom => de_casteljau(&self.bezier_controls(i), u),
+            SplineKind::BSpline => self
+                .bspline_factors(i, u)
+                .iter()
+                .fold(self.motors[i], |m, (f, _)| m.combine(*f)),
+        }
+    }
+    pub fn velocity(&self, t: Float) -> Line {
+        let (i, u) = self.segment(t);
+        match self.kind {
+            SplineKind::CatmullRom => de_casteljau_velocity(&self.bezier_controls(i), u),
+            //Each factor adds its own rate, seen through the factors applied after it
+            SplineKind::BSpline => self
+                .bspline_factors(i, u)
+                .iter()
+                .fold(Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0), |v, (f, rate)| {
+                    f.transform_line(v) + *rate
+                }),
+        }
+    }
+
+    //Tables for moving along the spline at constant speed, measuring either the distance covered
+    //by the moving origin or the angle turned
+    pub fn arc_length_table(&self, samples: usize, measure: Measure) -> ArcLengthTable {
+        let samples = samples.max(1);
+        let end = self.segments() as Float;
+        let mut params = Vec::with_capacity(samples + 1);
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut prev = self.sample(0.0);
+        let mut length = 0.0;
+        for s in 0..=samples {
+            let t = end * s as Float / samples as Float;
+            let m = self.sample(t);
+            length += measure.between(prev, m);
+            params.push(t);
+            lengths.push(length);
+            prev = m;
+        }
+        ArcLengthTable { params, lengths }
+    }
+
+    fn segment(&self, t: Float) -> (usize, Float) {
+        let t = t.clamp(0.0, self.segments() as Float);
+        let i = (t.floor() as usize).min(self.segments() - 1);
+        (i, t - i as Float)
+    }
+    //Catmull-Rom tangents are half the screw from the previous motor to the next, falling back to
+    //one sided differences at the ends
+    fn bezier_controls(&self, i: usize) -> [Motor; 4] {
+        let tangent = |j: usize| {
+            let prev = self.motors[j.saturating_sub(1)];
+            let next = self.motors[(j + 1).min(self.motors.len() - 1)];
+            let span = ((j + 1).min(self.motors.len() - 1) - j.saturating_sub(1)) as Float;
+            prev.inverse().combine(next).log() * (1.0 / span)
+        };
+        let (a, b) = (self.motors[i], self.motors[i + 1]);
+        [
+            a,
+            a.combine(Motor::exp(tangent(i) * (1.0 / 3.0))),
+            b.combine(Motor::exp(tangent(i + 1) * (-1.0 / 3.0))),
+            b,
+        ]
+    }
+    //The three factors applied after motors[i] along with their rates, following Kim, Kim and Shin
+    fn bspline_factors(&self, i: usize, u: Float) -> [(Motor, Line); 3] {
+        let u2 = u * u;
+        let u3 = u2 * u;
+        let basis = [
+            (
+                (5.0 + 3.0 * u - 3.0 * u2 + u3) / 6.0,
+                (3.0 - 6.0 * u + 3.0 * u2) / 6.0,
+            ),
+            (
+                (1.0 + 3.0 * u + 3.0 * u2 - 2.0 * u3) / 6.0,
+                (3.0 + 6.0 * u - 6.0 * u2) / 6.0,
+            ),
+            (u3 / 6.0, 3.0 * u2 / 6.0),
+        ];
+        [0, 1, 2].map(|j| {
+            let delta = self.motors[i + j]
+                .inverse()
+                .combine(self.motors[i + j + 1])
+                .log();
+            let (b, db) = basis[j];
+            (Motor::exp(delta * b), delta * db)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Measure {
+    Distance,
+    Angle,
+}
+impl Measure {
+    fn between(&self, a: Motor, b: Motor) -> Float {
+        match self {
+            Measure::Distance => {
+                let (p, q) = (a.transform(Point::ZERO), b.transform(Point::ZERO));
+                p.scaled().dist(q.scaled())
+            }
+            Measure::Angle => a.inverse().combine(b).log().weight_norm(),
+        }
+    }
+}
+
+//Cumulative lengths at increasing curve parameters, inverted by linear interpolation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcLengthTable {
+    params: Vec<Float>,
+    lengths: Vec<Float>,
+}
+impl ArcLengthTable {
+    pub fn total(&self) -> Float {
+        *self.lengths.last().unwrap()
+    }
+    //The curve parameter at which the curve has covered a length, clamped to the curve
+    pub fn param_at(&self, length: Float) -> Float {
+        let i = self.lengths.partition_point(|l| *l < length);
+        if i == 0 {
+            return self.params[0];
+        }
+        if i == self.lengths.len() {
+            return *self.params.last().unwrap();
+        }
+        let (l0, l1) = (self.lengths[i - 1], self.lengths[i]);
+        let f = if l1 > l0 {
+            (length - l0) / (l1 - l0)
+        } else {
+            0.0
+        };
+        self.params[i - 1] + (self.params[i] - self.params[i - 1]) * f
+    }
+    //The curve parameter a fraction of the way along the total length
+    pub fn param_at_fraction(&self, fraction: Float) -> Float {
+        self.param_at(fraction * self.total())
+    }
+}
@@ -0,0 +1,227 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{motor::Motor, Float};
+
+//How lookups behave outside of an edge's recorded history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extrapolation {
+    Error,
+    //Holds the first or last sample
+    Clamp,
+    //Continues the screw motion between the two nearest samples
+    Linear,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TfError {
+    UnknownFrame(String),
+    //The frames are in different trees
+    NotConnected(String, String),
+    //The child frame is an ancestor of the new parent
+    Cycle(String, String),
+    //The edge from a frame to its parent has no sample covering the time
+    Extrapolation { frame: String, time: f64 },
+}
+impl fmt::Display for TfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TfError::UnknownFrame(name) => write!(f, "unknown frame {name}"),
+            TfError::NotConnected(a, b) => write!(f, "frames {a} and {b} are not connected"),
+            TfError::Cycle(parent, child) => {
+                write!(
+                    f,
+                    "making {parent} the parent of {child} would create a cycle"
+                )
+            }
+            TfError::Extrapolation { frame, time } => {
+                write!(f, "no transform for frame {frame} at time {time}")
+            }
+        }
+    }
+}
+impl std::error::Error for TfError {}
+
+#[derive(Debug, Clone)]
+struct Edge {
+    parent: usize,
+    //Sorted by time. Motors move the child frame into the parent frame
+    samples: Vec<(f64, Motor)>,
+    is_static: bool,
+}
+
+//A tree of named frames whose edges are time stamped motors. Each motor is the pose of a child
+//frame in its parent frame, so it maps child coordinates into parent coordinates
+#[derive(Debug, Clone)]
+pub struct TransformBuffer {
+    pub extrapolation: Extrapolation,
+    frames: HashMap<String, usize>,
+    names: Vec<String>,
+    edges: Vec<Option<Edge>>,
+}
+impl TransformBuffer {
+    pub fn new(extrapolation: Extrapolation) -> Self {
+        Self {
+            extrapolation,
+            frames: HashMap::new(),
+            names: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    pub fn has_frame(&self, name: &str) -> bool {
+        self.frames.contains_key(name)
+    }
+    pub fn parent(&self, name: &str) -> Option<&str> {
+        let edge = self.edges[*self.frames.get(name)?].as_ref()?;
+        Some(&self.names[edge.parent])
+    }
+
+    //Records the pose of child in parent at a time. Giving a child a new parent drops its history
+    pub fn set_transform(
+        &mut self,
+        parent: &str,
+        child: &str,
+        time: f64,
+        motor: Motor,
+    ) -> Result<(), TfError> {
+        let edge = self.edge_mut(parent, child, false)?;
+        let i = edge.samples.partition_point(|(t, _)| *t <= time);
+        if i > 0 && edge.samples[i - 1].0 == time {
+            edge.samples[i - 1].1 = motor;
+        } else {
+            edge.samples.insert(i, (time, motor));
+        }
+        Ok(())
+    }
+    //A pose that holds at every time, such as a sensor mount
+    pub fn set_static_transform(
+        &mut self,
+        parent: &str,
+        child: &str,
+        motor: Motor,
+    ) -> Result<(), TfError> {
+        let edge = self.edge_mut(parent, child, true)?;
+        edge.samples.clear();
+        edge.samples.push((0.0, motor));
+        Ok(())
+    }
+    //Drops samples that are no longer needed to answer lookups at or after a time
+    pub fn prune_before(&mut self, time: f64) {
+        for edge in self.edges.iter_mut().flatten() {
+            if edge.is_static {
+                continue;
+            }
+            let keep_from = edge.samples.partition_point(|(t, _)| *t <= time);
+            edge.samples.drain(..keep_from.saturating_sub(1));
+        }
+    }
+
+    //The pose of source in target at a time, mapping source coordinates into target coordinates
+    pub fn lookup(&self, target: &str, source: &str, time: f64) -> Result<Motor, TfError> {
+        let target_id = self.id(target)?;
+        let source_id = self.id(source)?;
+
+        let source_chain = self.ancestors(source_id);
+        let target_chain = self.ancestors(target_id);
+        let common = source_chain
+            .iter()
+            .position(|f| target_chain.contains(f))
+            .ok_or_else(|| TfError::NotConnected(target.to_owned(), source.to_owned()))?;
+        let common_frame = source_chain[common];
+
+        let to_common = |chain: &[usize]| -> Result<Motor, TfError> {
+            let mut m = Motor::IDENTITY;
+            for &frame in chain.iter().take_while(|f| **f != common_frame) {
+                m = m.combine(self.edge_at(frame, time)?);
+            }
+            Ok(m)
+        };
+        let source_in_common = to_common(&source_chain)?;
+        let target_in_common = to_common(&target_chain)?;
+        Ok(source_in_common.combine(target_in_common.inverse()))
+    }
+
+    fn id(&self, name: &str) -> Result<usize, TfError> {
+        self.frames
+            .get(name)
+            .copied()
+            .ok_or_else(|| TfError::UnknownFrame(name.to_owned()))
+    }
+    fn id_or_insert(&mut self, name: &str) -> usize {
+        if let Some(id) = self.frames.get(name) {
+            return *id;
+        }
+        let id = self.names.len();
+        self.frames.insert(name.to_owned(), id);
+        self.names.push(name.to_owned());
+        self.edges.push(None);
+        id
+    }
+    //The frame itself followed by each of its ancestors up to the root
+    fn ancestors(&self, mut frame: usize) -> Vec<usize> {
+        let mut chain = vec![frame];
+        while let Some(edge) = &self.edges[frame] {
+            frame = edge.parent;
+            chain.push(frame);
+        }
+        chain
+    }
+    fn edge_mut(
+        &mut self,
+        parent: &str,
+        child: &str,
+        is_static: bool,
+    ) -> Result<&mut Edge, TfError> {
+        let parent_id = self.id_or_insert(parent);
+        let child_id = self.id_or_insert(child);
+        if self.ancestors(parent_id).contains(&child_id) {
+            return Err(TfError::Cycle(parent.to_owned(), child.to_owned()));
+        }
+
+        let edge = &mut self.edges[child_id];
+        if !matches!(edge, Some(e) if e.parent == parent_id && e.is_static == is_static) {
+            *edge = Some(Edge {
+                parent: parent_id,
+                samples: Vec::new(),
+                is_static,
+            });
+        }
+        Ok(edge.as_mut().unwrap())
+    }
+
+    //The motor from a frame to its parent at a time
+    fn edge_at(&self, frame: usize, time: f64) -> Result<Motor, TfError> {
+        let edge = self.edges[frame].as_ref().unwrap();
+        let samples = &edge.samples;
+        if edge.is_static {
+            return Ok(samples[0].1);
+        }
+
+        let i = samples.partition_point(|(t, _)| *t <= time);
+        if i > 0 && samples[i - 1].0 == time {
+            return Ok(samples[i - 1].1);
+        }
+        if i > 0 && i < samples.len() {
+            return Ok(interpolate(samples[i - 1], samples[i], time));
+        }
+
+        match self.extrapolation {
+            Extrapolation::Error => Err(TfError::Extrapolation {
+                frame: self.names[frame].clone(),
+                time,
+            }),
+            Extrapolation::Clamp => Ok(samples[if i == 0 { 0 } else { i - 1 }].1),
+            Extrapolation::Linear => Ok(match samples.len() {
+                1 => samples[0].1,
+                _ if i == 0 => interpolate(samples[0], samples[1], time),
+                n => interpolate(samples[n - 2], samples[n - 1], time),
+            }),
+        }
+    }
+}
+
+#[allow(clippy::unnecessary_cast)]
+fn interpolate(a: (f64, Motor), b: (f64, Motor), time: f64) -> Motor {
+    let t = (time - a.0) / (b.0 - a.0);
+    a.1.interpolate(b.1, t as Float)
+}
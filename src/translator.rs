@@ -0,0 +1,84 @@
+use crate::{motor::Motor, point::Point, rotor::Rotor, Float};
+
+//A motor with no rotation part. Like Motor, stores half of the translation
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+pub struct Translator {
+    pub mx: Float,
+    pub my: Float,
+    pub mz: Float,
+}
+impl Default for Translator {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+impl Translator {
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0);
+    pub const fn new(mx: Float, my: Float, mz: Float) -> Self {
+        Self { mx, my, mz }
+    }
+    #[inline]
+    pub fn from_translation(x: Float, y: Float, z: Float) -> Self {
+        Self::new(x * 0.5, y * 0.5, z * 0.5)
+    }
+
+    #[inline]
+    pub fn combine(&self, other: Translator) -> Self {
+        Self::new(self.mx + other.mx, self.my + other.my, self.mz + other.mz)
+    }
+    //Translate, then rotate
+    #[inline]
+    pub fn combine_rotor(&self, other: Rotor) -> Motor {
+        Motor::new(
+            other.vx,
+            other.vy,
+            other.vz,
+            other.vw,
+            other.vw * self.mx + other.vy * self.mz - other.vz * self.my,
+            other.vw * self.my + other.vz * self.mx - other.vx * self.mz,
+            other.vw * self.mz + other.vx * self.my - other.vy * self.mx,
+            -(other.vx * self.mx + other.vy * self.my + other.vz * self.mz),
+        )
+    }
+    #[inline]
+    pub fn combine_motor(&self, other: Motor) -> Motor {
+        Motor::new(
+            other.vx,
+            other.vy,
+            other.vz,
+            other.vw,
+            other.mx + other.vw * self.mx + other.vy * self.mz - other.vz * self.my,
+            other.my + other.vw * self.my + other.vz * self.mx - other.vx * self.mz,
+            other.mz + other.vw * self.mz + other.vx * self.my - other.vy * self.mx,
+            other.mw - (other.vx * self.mx + other.vy * self.my + other.vz * self.mz),
+        )
+    }
+
+    #[inline]
+    pub fn transform(&self, p: Point) -> Point {
+        Point::new(
+            p.x + 2.0 * p.w * self.mx,
+            p.y + 2.0 * p.w * self.my,
+            p.z + 2.0 * p.w * self.mz,
+            p.w,
+        )
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self::new(-self.mx, -self.my, -self.mz)
+    }
+
+    #[inline]
+    pub fn translation_euler(&self) -> Point {
+        Point::new(2.0 * self.mx, 2.0 * self.my, 2.0 * self.mz, 1.0)
+    }
+}
+impl From<Translator> for Motor {
+    fn from(value: Translator) -> Self {
+        Motor::new(0.0, 0.0, 0.0, 1.0, value.mx, value.my, value.mz, 0.0)
+    }
+}
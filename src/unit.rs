@@ -0,0 +1,200 @@
+use crate::{direction::Direction, line::Line, motor::Motor, plane::Plane, point::Point, Float};
+
+//A motor with a unit weight norm that satisfies the geometric constraint
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct UnitMotor(Motor);
+impl Default for UnitMotor {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+impl UnitMotor {
+    pub const IDENTITY: Self = Self(Motor::IDENTITY);
+
+    #[inline]
+    pub fn new(motor: Motor) -> Self {
+        Self(motor.normalized())
+    }
+    //The caller is responsible for the motor actually being normalized
+    #[inline]
+    pub const fn new_unchecked(motor: Motor) -> Self {
+        Self(motor)
+    }
+    #[inline]
+    pub fn from_translation(x: Float, y: Float, z: Float) -> Self {
+        Self(Motor::from_translation(x, y, z))
+    }
+    //Order is z, x, then y
+    #[inline]
+    pub fn from_euler_angles(x: Float, y: Float, z: Float) -> Self {
+        Self(Motor::from_euler_angles(x, y, z))
+    }
+    #[inline]
+    pub fn from_euler_pos_and_rot(
+        pos_x: Float,
+        pos_y: Float,
+        pos_z: Float,
+        rot_x: Float,
+        rot_y: Float,
+        rot_z: Float,
+    ) -> Self {
+        Self(Motor::from_euler_pos_and_rot(
+            pos_x, pos_y, pos_z, rot_x, rot_y, rot_z,
+        ))
+    }
+    //Unlike the Motor version, the axis doesn't need to be normalized. Returns None for a zero
+    //axis, which has no direction to rotate around
+    #[inline]
+    pub fn from_rotation_around_axis(axis: Direction, angle: Float) -> Option<Self> {
+        let magnitude = axis.magnitude();
+        if magnitude <= Float::EPSILON {
+            return None;
+        }
+        let axis = axis * (1.0 / magnitude);
+        Some(Self(Motor::from_rotation_around_axis(
+            axis.x, axis.y, axis.z, angle,
+        )))
+    }
+
+    #[inline]
+    pub fn motor(&self) -> Motor {
+        self.0
+    }
+
+    //Products of unit motors are unit, up to rounding error. Use renormalized after long chains
+    #[inline]
+    pub fn combine(&self, other: UnitMotor) -> Self {
+        Self(self.0.combine(other.0))
+    }
+    #[inline]
+    pub fn renormalized(&self) -> Self {
+        Self(self.0.normalized())
+    }
+
+    #[inline]
+    pub fn transform(&self, p: Point) -> Point {
+        let m = &self.0;
+        let ax = m.vy * p.z - m.vz * p.y + p.w * m.mx;
+        let ay = m.vz * p.x - m.vx * p.z + p.w * m.my;
+        let az = m.vx * p.y - m.vy * p.x + p.w * m.mz;
+
+        Point::new(
+            p.x + 2.0 * (m.vw * ax + (m.vy * az - m.vz * ay) - m.mw * p.w * m.vx),
+            p.y + 2.0 * (m.vw * ay + (m.vz * ax - m.vx * az) - m.mw * p.w * m.vy),
+            p.z + 2.0 * (m.vw * az + (m.vx * ay - m.vy * ax) - m.mw * p.w * m.vz),
+            p.w,
+        )
+    }
+    #[inline]
+    pub fn transform_direction(&self, d: Direction) -> Direction {
+        let m = &self.0;
+        let ax = m.vy * d.z - m.vz * d.y;
+        let ay = m.vz * d.x - m.vx * d.z;
+        let az = m.vx * d.y - m.vy * d.x;
+
+        Direction::new(
+            d.x + 2.0 * (m.vw * ax + (m.vy * az - m.vz * ay)),
+            d.y + 2.0 * (m.vw * ay + (m.vz * ax - m.vx * az)),
+            d.z + 2.0 * (m.vw * az + (m.vx * ay - m.vy * ax)),
+        )
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self(self.0.reverse())
+    }
+
+    #[inline]
+    pub fn translation_euler(&self) -> Point {
+        let m = &self.0;
+        Point::new(
+            2.0 * (m.vw * m.mx + m.vy * m.mz - m.vz * m.my - m.mw * m.vx),
+            2.0 * (m.vw * m.my + m.vz * m.mx - m.vx * m.mz - m.mw * m.vy),
+            2.0 * (m.vw * m.mz + m.vx * m.my - m.vy * m.mx - m.mw * m.vz),
+            1.0,
+        )
+    }
+}
+impl From<UnitMotor> for Motor {
+    fn from(value: UnitMotor) -> Self {
+        value.0
+    }
+}
+
+//A line with a unit direction that satisfies the Plücker condition
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct UnitLine(Line);
+impl UnitLine {
+    #[inline]
+    pub fn new(line: Line) -> Self {
+        Self(line.normalized())
+    }
+    //The caller is responsible for the line actually being normalized
+    #[inline]
+    pub const fn new_unchecked(line: Line) -> Self {
+        Self(line)
+    }
+
+    #[inline]
+    pub fn line(&self) -> Line {
+        self.0
+    }
+    #[inline]
+    pub fn direction(&self) -> Direction {
+        self.0.direction()
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, p: Point) -> Float {
+        let l = &self.0;
+        let x = p.y * l.vz - p.z * l.vy - p.w * l.mx;
+        let y = p.z * l.vx - p.x * l.vz - p.w * l.my;
+        let z = p.x * l.vy - p.y * l.vx - p.w * l.mz;
+        (x * x + y * y + z * z).sqrt() / p.w.abs()
+    }
+}
+impl From<UnitLine> for Line {
+    fn from(value: UnitLine) -> Self {
+        value.0
+    }
+}
+
+//A plane with a unit normal
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct UnitPlane(Plane);
+impl UnitPlane {
+    #[inline]
+    pub fn new(plane: Plane) -> Self {
+        Self(plane.normalized())
+    }
+    //The caller is responsible for the plane actually being normalized
+    #[inline]
+    pub const fn new_unchecked(plane: Plane) -> Self {
+        Self(plane)
+    }
+
+    #[inline]
+    pub fn plane(&self) -> Plane {
+        self.0
+    }
+    #[inline]
+    pub fn normal(&self) -> Direction {
+        self.0.normal()
+    }
+
+    //Positive on the side the normal points to. Like Plane::signed_distance, p can't be ideal
+    #[inline]
+    pub fn signed_distance(&self, p: Point) -> Float {
+        debug_assert_ne!(p.w, 0.0);
+        let g = &self.0;
+        (g.x * p.x + g.y * p.y + g.z * p.z + g.w * p.w) / p.w
+    }
+}
+impl From<UnitPlane> for Plane {
+    fn from(value: UnitPlane) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{
+    batch::MotorMatrix, direction::Direction, motor::Motor, plane::Plane, point::Point, Float,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarFormat {
+    F32,
+    F16,
+}
+impl ScalarFormat {
+    pub const fn size(self) -> usize {
+        match self {
+            ScalarFormat::F32 => 4,
+            ScalarFormat::F16 => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalKind {
+    //Three components, rotated like a Direction
+    Direction,
+    //Four components, transformed like a Plane
+    Plane,
+}
+
+//Byte offset of an attribute from the start of each vertex
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexAttribute {
+    pub offset: usize,
+    pub format: ScalarFormat,
+}
+impl VertexAttribute {
+    pub const fn new(offset: usize, format: ScalarFormat) -> Self {
+        Self { offset, format }
+    }
+}
+
+//Positions and tangents use three components. A fourth tangent component, such as a
+//handedness sign, is left untouched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexLayout {
+    pub stride: usize,
+    pub position: Option<VertexAttribute>,
+    pub normal: Option<(VertexAttribute, NormalKind)>,
+    pub tangent: Option<VertexAttribute>,
+}
+impl VertexLayout {
+    pub const fn new(stride: usize) -> Self {
+        Self {
+            stride,
+            position: None,
+            normal: None,
+            tangent: None,
+        }
+    }
+    pub const fn with_position(mut self, offset: usize, format: ScalarFormat) -> Self {
+        self.position = Some(VertexAttribute::new(offset, format));
+        self
+    }
+    pub const fn with_normal(
+        mut self,
+        offset: usize,
+        format: ScalarFormat,
+        kind: NormalKind,
+    ) -> Self {
+        self.normal = Some((VertexAttribute::new(offset, format), kind));
+        self
+    }
+    pub const fn with_tangent(mut self, offset: usize, format: ScalarFormat) -> Self {
+        self.tangent = Some(VertexAttribute::new(offset, format));
+        self
+    }
+
+    fn assert_fits(&self, attribute: Option<VertexAttribute>, components: usize) {
+        if let Some(a) = attribute {
+            assert!(
+                a.offset + a.format.size() * components <= self.stride,
+                "Vertex attribute extends past the stride"
+            );
+        }
+    }
+}
+
+impl Motor {
+    //Transforms every vertex of an interleaved buffer in place. Trailing bytes that don't make up
+    //a whole vertex are ignored
+    pub fn transform_vertex_buffer(&self, buf: &mut [u8], layout: &VertexLayout) {
+        assert!(layout.stride > 0, "Vertex stride must be nonzero");
+        layout.assert_fits(layout.position, 3);
+        layout.assert_fits(
+            layout.normal.map(|n| n.0),
+            match layout.normal {
+                Some((_, NormalKind::Plane)) => 4,
+                _ => 3,
+            },
+        );
+        layout.assert_fits(layout.tangent, 3);
+
+        let m = MotorMatrix::new(self);
+        let mut out = [0.0; 4];
+
+        for vertex in buf.chunks_exact_mut(layout.stride) {
+            if let Some(a) = layout.position {
+                let [x, y, z, _] = read(vertex, a);
+                m.transform(Point::from_position(x, y, z))
+                    .copy_to_buffer_32(&mut out);
+                write(vertex, a, &out[..3]);
+            }
+            if let Some((a, kind)) = layout.normal {
+                let [x, y, z, w] = read(vertex, a);
+                match kind {
+                    NormalKind::Direction => {
+                        let d = m.transform_direction(Direction::new(x, y, z));
+                        write(vertex, a, &d.into_buffer_32());
+                    }
+                    NormalKind::Plane => {
+                        let p = m.transform_plane(Plane::new(x, y, z, w));
+                        write(vertex, a, &p.into_buffer_32());
+                    }
+                }
+            }
+            if let Some(a) = layout.tangent {
+                let [x, y, z, _] = read(vertex, a);
+                let d = m.transform_direction(Direction::new(x, y, z));
+                write(vertex, a, &d.into_buffer_32());
+            }
+        }
+    }
+}
+
+//Reads up to four components, stopping at the end of the vertex
+#[allow(clippy::unnecessary_cast)]
+fn read(vertex: &[u8], a: VertexAttribute) -> [Float; 4] {
+    let mut r = [0.0; 4];
+    let size = a.format.size();
+    for (i, bytes) in vertex[a.offset..].chunks_exact(size).take(4).enumerate() {
+        r[i] = match a.format {
+            ScalarFormat::F32 => f32::from_ne_bytes(bytes.try_into().unwrap()),
+            ScalarFormat::F16 => f16_to_f32(u16::from_ne_bytes(bytes.try_into().unwrap())),
+        } as Float;
+    }
+    r
+}
+
+fn write(vertex: &mut [u8], a: VertexAttribute, values: &[f32]) {
+    let size = a.format.size();
+    for (bytes, v) in vertex[a.offset..].chunks_exact_mut(size).zip(values) {
+        match a.format {
+            ScalarFormat::F32 => bytes.copy_from_slice(&v.to_ne_bytes()),
+            ScalarFormat::F16 => bytes.copy_from_slice(&f32_to_f16(*v).to_ne_bytes()),
+        }
+    }
+}
+
+//Rounds to nearest, ties to even
+pub fn f32_to_f16(v: f32) -> u16 {
+    let bits = v.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp_field = (bits >> 23) & 0xff;
+    let mant = bits & 0x7f_ffff;
+
+    if exp_field == 0xff {
+        return sign | 0x7c00 | if mant != 0 { 0x200 } else { 0 };
+    }
+    let exp = exp_field as i32 - 127 + 15;
+    if exp >= 0x1f {
+        return sign | 0x7c00;
+    }
+    if exp <= 0 {
+        if exp < -10 {
+            return sign;
+        }
+        let mant = mant | 0x80_0000;
+        let shift = (14 - exp) as u32;
+        let round_bit = 1 << (shift - 1);
+        let mut half = (mant >> shift) as u16;
+        if mant & round_bit != 0 && mant & (3 * round_bit - 1) != 0 {
+            half += 1;
+        }
+        return sign | half;
+    }
+
+    let mut half = sign | ((exp as u16) << 10) | (mant >> 13) as u16;
+    if mant & 0x1000 != 0 && mant & 0x2fff != 0 {
+        half += 1;
+    }
+    half
+}
+
+pub fn f16_to_f32(h: u16) -> f32 {
+    let sign = ((h & 0x8000) as u32) << 16;
+    let exp = ((h >> 10) & 0x1f) as u32;
+    let mant = (h & 0x3ff) as u32;
+
+    match exp {
+        0 => {
+            let v = mant as f32 * (1.0 / (1 << 24) as f32);
+            if sign != 0 {
+                -v
+            } else {
+                v
+            }
+        }
+        0x1f => f32::from_bits(sign | 0x7f80_0000 | (mant << 13)),
+        _ => f32::from_bits(sign | ((exp + 112) << 23) | (mant << 13)),
+    }
+}